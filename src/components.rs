@@ -26,3 +26,138 @@ impl Component for TargetIllumination {
 impl Component for Velocity {
     type Storage = VecStorage<Self>;
 }
+
+impl Component for Trajectory {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Acceleration {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for MotionModel {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Orientation {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Boresight {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for GeodeticPosition {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for RainCell {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Waveform {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for SignalProcessor {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Receiver {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Jammer {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for NoiseJamming {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for JammingReport {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for DeceptionJammer {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for StandOffJammer {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Chaff {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for TowedDecoy {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Dispenser {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for FalseTarget {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Eccm {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for EsmReceiver {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Elint {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for WaveSource {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Bistatic {
+    type Storage = VecStorage<Self>;
+}
+
+impl Component for Scan {
+    type Storage = VecStorage<Self>;
+}
+
+/// Registers every component in the crate, so entities can be built before any system has been set up
+pub fn register_components(world: &mut World) {
+    world.register::<Antenna>();
+    world.register::<EMWave>();
+    world.register::<Position>();
+    world.register::<RCS>();
+    world.register::<TargetIllumination>();
+    world.register::<Velocity>();
+    world.register::<Trajectory>();
+    world.register::<Acceleration>();
+    world.register::<MotionModel>();
+    world.register::<Orientation>();
+    world.register::<Boresight>();
+    world.register::<GeodeticPosition>();
+    world.register::<RainCell>();
+    world.register::<Waveform>();
+    world.register::<SignalProcessor>();
+    world.register::<Receiver>();
+    world.register::<Jammer>();
+    world.register::<NoiseJamming>();
+    world.register::<JammingReport>();
+    world.register::<DeceptionJammer>();
+    world.register::<StandOffJammer>();
+    world.register::<Chaff>();
+    world.register::<TowedDecoy>();
+    world.register::<Dispenser>();
+    world.register::<FalseTarget>();
+    world.register::<Eccm>();
+    world.register::<EsmReceiver>();
+    world.register::<Elint>();
+    world.register::<WaveSource>();
+    world.register::<Bistatic>();
+    world.register::<Scan>();
+}
@@ -21,4 +21,69 @@ mod calculate_rcs;
 pub use calculate_rcs::*;
 
 mod calculate_range;
-pub use calculate_range::*;
\ No newline at end of file
+pub use calculate_range::*;
+mod steer_velocity;
+pub use steer_velocity::*;
+
+mod coordinated_turn;
+pub use coordinated_turn::*;
+
+mod singer_acceleration;
+pub use singer_acceleration::*;
+
+mod attitude;
+pub use attitude::*;
+
+mod geodetic;
+pub use geodetic::*;
+
+mod line_of_sight;
+pub use line_of_sight::*;
+
+mod terrain;
+pub use terrain::*;
+
+mod atmospheric_attenuation;
+pub use atmospheric_attenuation::*;
+
+mod multipath;
+pub use multipath::*;
+
+mod clutter;
+pub use clutter::*;
+
+mod rain_cell;
+pub use rain_cell::*;
+
+mod pulse_doppler;
+pub use pulse_doppler::*;
+
+mod noise_jamming;
+pub use noise_jamming::*;
+
+mod gate_pull_off;
+pub use gate_pull_off::*;
+
+mod jammer_power;
+pub use jammer_power::*;
+
+mod expendables;
+pub use expendables::*;
+
+mod eccm;
+pub use eccm::*;
+
+mod elint;
+pub use elint::*;
+
+mod passive_geolocation;
+pub use passive_geolocation::*;
+
+mod bistatic;
+pub use bistatic::*;
+
+mod mutual_interference;
+pub use mutual_interference::*;
+
+mod scenario;
+pub use scenario::*;
@@ -0,0 +1,114 @@
+use super::*;
+
+// ITU-R P.838 rain coefficients (frequency GHz, k, alpha) for horizontal polarization
+const RAIN_COEFFICIENTS: [(f32, f32, f32); 17] = [
+    (1.0, 0.0000259, 0.9691),
+    (2.0, 0.0000847, 1.0664),
+    (4.0, 0.0001071, 1.6009),
+    (6.0, 0.0007056, 1.5900),
+    (8.0, 0.004115, 1.3905),
+    (10.0, 0.01217, 1.2571),
+    (12.0, 0.02386, 1.1825),
+    (15.0, 0.04481, 1.1233),
+    (20.0, 0.09164, 1.0568),
+    (25.0, 0.1571, 0.9991),
+    (30.0, 0.2403, 0.9485),
+    (35.0, 0.3374, 0.9047),
+    (40.0, 0.4431, 0.8673),
+    (50.0, 0.6600, 0.8084),
+    (60.0, 0.8606, 0.7656),
+    (80.0, 1.1704, 0.7115),
+    (100.0, 1.3671, 0.6815),
+];
+
+/// Sea level oxygen and water vapour absorption in dB/km, from the ITU-R P.676 simplified model (valid below 57 GHz)
+pub fn gaseous_attenuation(f_ghz: f32, water_vapour_density: f32) -> f32 {
+    let f2 = f_ghz.powi(2);
+    let oxygen = (7.19e-3 + 6.09 / (f2 + 0.227) + 4.81 / ((f_ghz - 57.0).powi(2) + 1.50)) * f2 * 1e-3;
+    let rho = water_vapour_density;
+    let vapour = (0.050 + 0.0021 * rho
+        + 3.6 / ((f_ghz - 22.2).powi(2) + 8.5)
+        + 10.6 / ((f_ghz - 183.3).powi(2) + 9.0)
+        + 8.9 / ((f_ghz - 325.4).powi(2) + 26.3)) * f2 * rho * 1e-4;
+    oxygen + vapour
+}
+
+/// Rain attenuation in dB/km, k * R^alpha from ITU-R P.838 with coefficients interpolated on log frequency
+pub fn rain_attenuation(f_ghz: f32, rain_rate: f32) -> f32 {
+    if rain_rate <= 0.0 || f_ghz <= 0.0 {
+        return 0.0;
+    }
+    let first = RAIN_COEFFICIENTS[0];
+    let last = RAIN_COEFFICIENTS[RAIN_COEFFICIENTS.len() - 1];
+    let (k, alpha) = if f_ghz <= first.0 {
+        // Below 1 GHz rain loss falls off with the square of frequency
+        (first.1 * (f_ghz / first.0).powi(2), first.2)
+    } else if f_ghz >= last.0 {
+        (last.1, last.2)
+    } else {
+        let i = RAIN_COEFFICIENTS.iter().position(|c| c.0 > f_ghz).unwrap();
+        let (lo, hi) = (RAIN_COEFFICIENTS[i - 1], RAIN_COEFFICIENTS[i]);
+        let t = (f_ghz.ln() - lo.0.ln()) / (hi.0.ln() - lo.0.ln());
+        ((lo.1.ln() + t * (hi.1.ln() - lo.1.ln())).exp(), lo.2 + t * (hi.2 - lo.2))
+    };
+    k * rain_rate.powf(alpha)
+}
+
+/// Cloud and fog attenuation in dB/km, from the ITU-R P.840 double Debye model
+pub fn fog_attenuation(f_ghz: f32, fog_density: f32, temperature: f32) -> f32 {
+    if fog_density <= 0.0 || f_ghz <= 0.0 {
+        return 0.0;
+    }
+    let theta = 300.0 / (temperature + 273.15);
+    let e0 = 77.66 + 103.3 * (theta - 1.0);
+    let (e1, e2) = (5.48, 3.51);
+    let fp = 20.09 - 142.0 * (theta - 1.0) + 294.0 * (theta - 1.0).powi(2);
+    let fs = 590.0 - 1500.0 * (theta - 1.0);
+    let e_imag = f_ghz * (e0 - e1) / (fp * (1.0 + (f_ghz / fp).powi(2)))
+        + f_ghz * (e1 - e2) / (fs * (1.0 + (f_ghz / fs).powi(2)));
+    let e_real = (e0 - e1) / (1.0 + (f_ghz / fp).powi(2)) + (e1 - e2) / (1.0 + (f_ghz / fs).powi(2)) + e2;
+    let eta = (2.0 + e_real) / e_imag;
+    let k_l = 0.819 * f_ghz / (e_imag * (1.0 + eta.powi(2)));
+    k_l * fog_density
+}
+
+/// Total specific attenuation, in dB/km, for a frequency in Hz under the given weather
+pub fn specific_attenuation(frequency: f32, weather: &Weather) -> f32 {
+    let f_ghz = frequency / 1e9;
+    gaseous_attenuation(f_ghz, weather.water_vapour_density)
+        + rain_attenuation(f_ghz, weather.rain_rate)
+        + fog_attenuation(f_ghz, weather.fog_density, weather.temperature)
+}
+
+/// Returns the fraction of power, 0 - 1, left after a one way path of `range` meters
+pub fn atmospheric_loss(frequency: f32, range: f32, weather: &Weather) -> f32 {
+    let loss_db = specific_attenuation(frequency, weather) * range / 1000.0;
+    10.0_f32.powf(-loss_db / 10.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gaseous() {
+        // X-band is a little over 0.01 dB/km, the water vapour line at 22 GHz is far stronger
+        let x_band = gaseous_attenuation(10.0, 7.5);
+        assert!(x_band > 0.01 && x_band < 0.02);
+        assert!(gaseous_attenuation(22.2, 7.5) > 0.15);
+        assert!(gaseous_attenuation(1e-7, 7.5) < 1e-12);
+    }
+
+    #[test]
+    fn test_weather() {
+        assert!((rain_attenuation(10.0, 10.0) - 0.2195).abs() < 1e-3);
+        assert!(rain_attenuation(15.0, 10.0) > rain_attenuation(12.0, 10.0));
+        let fog = fog_attenuation(10.0, 0.5, 15.0);
+        assert!(fog > 0.03 && fog < 0.06);
+
+        // Two way over 100 km at X-band in moderate rain is a large loss
+        let weather = Weather{rain_rate: 4.0, ..Weather::default()};
+        let two_way = atmospheric_loss(10e9, 100_000.0, &weather).powi(2);
+        assert!(two_way < 0.05);
+    }
+}
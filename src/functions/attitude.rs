@@ -0,0 +1,92 @@
+use super::*;
+
+/// Unit vector pointing along an azimuth and elevation, both in degrees
+pub fn direction_vector(azimuth: f32, elevation: f32) -> [f32; 3] {
+    let (sin_az, cos_az) = azimuth.to_radians().sin_cos();
+    let (sin_el, cos_el) = elevation.to_radians().sin_cos();
+    [cos_el * cos_az, cos_el * sin_az, sin_el]
+}
+
+/// Returns the azimuth (0 - 360) and elevation, in degrees, of a vector
+pub fn vector_angles(v: [f32; 3]) -> (f32, f32) {
+    let mut azimuth = v[1].atan2(v[0]).to_degrees();
+    if azimuth < 0.0 {
+        azimuth += 360.0;
+    }
+    let elevation = v[2].atan2((v[0].powi(2) + v[1].powi(2)).sqrt()).to_degrees();
+    (azimuth, elevation)
+}
+
+/// Rotates a vector from the platform's body frame (x nose, y left wing, z up) into the world frame
+pub fn body_to_world(orientation: &Orientation, v: [f32; 3]) -> [f32; 3] {
+    let (sin_r, cos_r) = orientation.roll.to_radians().sin_cos();
+    let (sin_p, cos_p) = orientation.pitch.to_radians().sin_cos();
+    let (sin_y, cos_y) = orientation.yaw.to_radians().sin_cos();
+    // Roll about the nose
+    let v = [v[0], cos_r * v[1] - sin_r * v[2], sin_r * v[1] + cos_r * v[2]];
+    // Pitch about the wings
+    let v = [cos_p * v[0] - sin_p * v[2], v[1], sin_p * v[0] + cos_p * v[2]];
+    // Yaw about the vertical
+    [cos_y * v[0] - sin_y * v[1], sin_y * v[0] + cos_y * v[1], v[2]]
+}
+
+/// Rotates a world frame vector into the platform's body frame
+pub fn world_to_body(orientation: &Orientation, v: [f32; 3]) -> [f32; 3] {
+    let (sin_r, cos_r) = orientation.roll.to_radians().sin_cos();
+    let (sin_p, cos_p) = orientation.pitch.to_radians().sin_cos();
+    let (sin_y, cos_y) = orientation.yaw.to_radians().sin_cos();
+    let v = [cos_y * v[0] + sin_y * v[1], -sin_y * v[0] + cos_y * v[1], v[2]];
+    let v = [cos_p * v[0] + sin_p * v[2], v[1], -sin_p * v[0] + cos_p * v[2]];
+    [v[0], cos_r * v[1] + sin_r * v[2], -sin_r * v[1] + cos_r * v[2]]
+}
+
+/// Returns the world azimuth an antenna is pointing.
+/// Antennas without a boresight point along `Position::direction`.
+/// A boresight is relative to the platform's orientation, or to `Position::direction` without one.
+pub fn antenna_direction(pos: &Position, orientation: Option<&Orientation>, boresight: Option<&Boresight>) -> f32 {
+    match (orientation, boresight) {
+        (_, None) => pos.direction,
+        (None, Some(bore)) => (pos.direction + bore.azimuth).rem_euclid(360.0),
+        (Some(orient), Some(bore)) => {
+            vector_angles(body_to_world(orient, direction_vector(bore.azimuth, bore.elevation))).0
+        }
+    }
+}
+
+/// Returns the aspect azimuth and elevation, in degrees, of a world direction in the platform's body frame
+pub fn body_aspect(orientation: &Orientation, azimuth: f32, elevation: f32) -> (f32, f32) {
+    vector_angles(world_to_body(orientation, direction_vector(azimuth, elevation)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attitude() {
+        let level = Orientation{yaw: 90.0, pitch: 0.0, roll: 0.0};
+        let (az, el) = vector_angles(body_to_world(&level, [1.0, 0.0, 0.0]));
+        assert!((az - 90.0).abs() < 1e-3);
+        assert!(el.abs() < 1e-3);
+
+        // Rolled right 90 degrees, a wave arriving from the right comes in through the top of the airframe
+        let banked = Orientation{yaw: 0.0, pitch: 0.0, roll: 90.0};
+        let (_, el) = body_aspect(&banked, 270.0, 0.0);
+        assert!((el - 90.0).abs() < 1e-3);
+
+        // Round trip through the body frame
+        let orient = Orientation{yaw: 30.0, pitch: 10.0, roll: -20.0};
+        let v = world_to_body(&orient, body_to_world(&orient, [0.2, 0.3, 0.9]));
+        assert!((v[0] - 0.2).abs() < 1e-5 && (v[1] - 0.3).abs() < 1e-5 && (v[2] - 0.9).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_antenna_direction() {
+        let pos = Position{x: 0.0, y: 0.0, z: 0.0, direction: 350.0};
+        let bore = Boresight{azimuth: 20.0, elevation: 0.0};
+        let ship = Orientation{yaw: 100.0, pitch: 0.0, roll: 0.0};
+        assert_eq!(antenna_direction(&pos, None, None), 350.0);
+        assert_eq!(antenna_direction(&pos, None, Some(&bore)), 10.0);
+        assert!((antenna_direction(&pos, Some(&ship), Some(&bore)) - 120.0).abs() < 1e-3);
+    }
+}
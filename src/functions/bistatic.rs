@@ -0,0 +1,62 @@
+use super::*;
+
+fn unit_from(from: &Position, to: &Position) -> [f32; 3] {
+    let v = [to.x - from.x, to.y - from.y, to.z - from.z];
+    let norm = (v[0].powi(2) + v[1].powi(2) + v[2].powi(2)).sqrt();
+    if norm > 0.0 { [v[0] / norm, v[1] / norm, v[2] / norm] } else { [0.0; 3] }
+}
+
+/// Range sum R_t + R_r, in meters, from a transmitter to a target and on to a receiver
+pub fn bistatic_range(transmitter: &Position, target: &Position, receiver: &Position) -> f32 {
+    slant_range(transmitter, target) + slant_range(target, receiver)
+}
+
+/// Angle, in degrees, between the directions from a target to the transmitter and to the receiver.
+/// Zero when monostatic, 180 for forward scatter.
+pub fn bistatic_angle(transmitter: &Position, target: &Position, receiver: &Position) -> f32 {
+    let to_tx = unit_from(target, transmitter);
+    let to_rx = unit_from(target, receiver);
+    let cos_beta = to_tx[0] * to_rx[0] + to_tx[1] * to_rx[1] + to_tx[2] * to_rx[2];
+    cos_beta.clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+/// Doppler shift, in Hz, of a moving target's echo, from the rate of change of the range sum
+pub fn bistatic_doppler(frequency: f32, velocity: &Velocity, transmitter: &Position, target: &Position, receiver: &Position) -> f32 {
+    let from_tx = unit_from(transmitter, target);
+    let from_rx = unit_from(receiver, target);
+    let rate = velocity.x * (from_tx[0] + from_rx[0]) + velocity.y * (from_tx[1] + from_rx[1]) + velocity.z * (from_tx[2] + from_rx[2]);
+    -frequency * rate / C
+}
+
+/// Azimuth and elevation, in degrees, of the bisector of the bistatic angle as seen from the target.
+/// By the monostatic-bistatic equivalence theorem, the bistatic RCS is close to the monostatic RCS
+/// along the bisector for bistatic angles below about 90 degrees.
+pub fn bisector_direction(transmitter: &Position, target: &Position, receiver: &Position) -> (f32, f32) {
+    let to_tx = unit_from(target, transmitter);
+    let to_rx = unit_from(target, receiver);
+    vector_angles([to_tx[0] + to_rx[0], to_tx[1] + to_rx[1], to_tx[2] + to_rx[2]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bistatic_geometry() {
+        let tx = Position{x: -1000.0, y: 0.0, z: 0.0, direction: 0.0};
+        let rx = Position{x: 1000.0, y: 0.0, z: 0.0, direction: 0.0};
+        let target = Position{x: 0.0, y: 1000.0, z: 0.0, direction: 0.0};
+        assert!((bistatic_range(&tx, &target, &rx) - 2.0 * 1000.0 * 2.0_f32.sqrt()).abs() < 1e-2);
+        assert!((bistatic_angle(&tx, &target, &rx) - 90.0).abs() < 1e-3);
+        let (azimuth, elevation) = bisector_direction(&tx, &target, &rx);
+        assert!((azimuth - 270.0).abs() < 1e-3 && elevation.abs() < 1e-3);
+
+        // Flying along the baseline's perpendicular bisector the range sum grows fastest
+        let away = Velocity{x: 0.0, y: 100.0, z: 0.0};
+        let expected = -1.0e9 * 100.0 * 2.0_f32.sqrt() / C;
+        assert!((bistatic_doppler(1.0e9, &away, &tx, &target, &rx) - expected).abs() < 1e-2);
+        // Monostatic reduces to the two way Doppler of the radial velocity
+        let radial = 100.0 / 2.0_f32.sqrt();
+        assert!((bistatic_doppler(1.0e9, &away, &rx, &target, &rx) + 2.0 * 1.0e9 * radial / C).abs() < 1.0);
+    }
+}
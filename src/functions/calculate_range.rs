@@ -2,7 +2,13 @@ use super::*;
 
 /// Calculates range using Euclidian distance
 pub fn calculate_range(pos1: &Position, pos2: &Position) -> f32 {
-    return ((pos1.x - pos2.x).powi(2) + (pos1.y - pos2.y).powi(2)).sqrt();
+    ((pos1.x - pos2.x).powi(2) + (pos1.y - pos2.y).powi(2)).sqrt()
+}
+
+/// Calculates the straight line distance including the height difference, the path length a wave travels.
+/// Exact for geodetic entities too, since Position is a local east / north / up frame.
+pub fn slant_range(pos1: &Position, pos2: &Position) -> f32 {
+    ((pos1.x - pos2.x).powi(2) + (pos1.y - pos2.y).powi(2) + (pos1.z - pos2.z).powi(2)).sqrt()
 }
 
 #[cfg(test)]
@@ -10,6 +16,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn test_range() {
         let radar = Position{
             x: 0.0,
@@ -27,6 +34,12 @@ mod tests {
 
         assert_eq!(calculate_range(&radar, &target), 141.42135623731);
     }
-}
-
 
+    #[test]
+    fn test_slant_range() {
+        let radar = Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0};
+        let target = Position{x: 300.0, y: 0.0, z: 400.0, direction: 0.0};
+        assert_eq!(slant_range(&radar, &target), 500.0);
+        assert_eq!(calculate_range(&radar, &target), 300.0);
+    }
+}
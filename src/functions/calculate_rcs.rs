@@ -1,6 +1,6 @@
 // use super::*;
 
-pub fn calculate_rcs(angle: f32, rcs_angles: &Vec<f32>, rcs_values: &Vec<f32>) -> f32 {
+pub fn calculate_rcs(angle: f32, rcs_angles: &[f32], rcs_values: &[f32]) -> f32 {
     let mut refl_pwr: f32 = -1.0;
     // Check to see if the rcs has a power specified for the angle
     if rcs_angles.contains(&angle) {
@@ -18,7 +18,7 @@ pub fn calculate_rcs(angle: f32, rcs_angles: &Vec<f32>, rcs_values: &Vec<f32>) -
             refl_pwr = rcs_values[rcs_values.len()-1];
         }
     }
-    return refl_pwr;
+    refl_pwr
 }
 
 #[cfg(test)]
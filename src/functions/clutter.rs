@@ -0,0 +1,153 @@
+use super::*;
+use rand::Rng;
+use rand_distr::{Exp1, Gamma, Weibull};
+
+/// Returns gamma, in dB, for a terrain type (Barton's constant gamma values)
+pub fn terrain_gamma(terrain: TerrainType) -> f32 {
+    match terrain {
+        TerrainType::Desert => -20.0,
+        TerrainType::Farmland => -15.0,
+        TerrainType::WoodedHills => -10.0,
+        TerrainType::Mountains => -5.0,
+        TerrainType::Urban => -5.0,
+        TerrainType::Custom(gamma) => gamma,
+    }
+}
+
+/// Land clutter reflectivity, sigma0 (m^2/m^2), from the constant gamma model
+pub fn constant_gamma_sigma0(terrain: TerrainType, grazing: f32) -> f32 {
+    10.0_f32.powf(terrain_gamma(terrain) / 10.0) * grazing.to_radians().sin()
+}
+
+/// Sea clutter reflectivity, sigma0 (m^2/m^2), from the GIT model.
+/// `look_angle` is the angle, in degrees, between the radar's look direction and the upwind direction.
+pub fn git_sea_sigma0(sea_state: f32, grazing: f32, look_angle: f32, wavelength: f32, polarization: Polarization) -> f32 {
+    let psi = grazing.to_radians();
+    let wind_speed = 3.16 * sea_state.max(0.0).powf(0.8);
+    let h_av = 0.00452 * wind_speed.powf(2.5);
+    // Interference, upwind and wind speed factors
+    let sigma_phi = (14.4 * wavelength + 5.5) * psi * h_av / wavelength;
+    let a_i = sigma_phi.powi(4) / (1.0 + sigma_phi.powi(4));
+    let a_u = (0.2 * look_angle.to_radians().cos() * (1.0 - 2.8 * psi) * (wavelength + 0.015).powf(-0.4)).exp();
+    let a_w = (1.94 * wind_speed / (1.0 + wind_speed / 15.4)).powf(1.1 * (wavelength + 0.015).powf(-0.4));
+    let horizontal = 3.9e-6 * wavelength * psi.powf(0.4) * a_i * a_u * a_w;
+    match polarization {
+        Polarization::Horizontal => horizontal,
+        Polarization::Vertical => {
+            let horizontal_db = 10.0 * horizontal.log10();
+            let vertical_db = if wavelength > 0.1 {
+                // Below 3 GHz
+                horizontal_db - 1.05 * (h_av + 0.015).ln() + 1.09 * wavelength.ln() + 1.27 * (psi + 0.0001).ln() + 9.70
+            } else {
+                horizontal_db - 1.73 * (h_av + 0.015).ln() + 3.76 * wavelength.ln() + 2.46 * (psi + 0.0001).ln() + 22.2
+            };
+            10.0_f32.powf(vertical_db / 10.0)
+        }
+    }
+}
+
+/// Gamma function by the Lanczos approximation
+pub fn gamma_function(x: f32) -> f32 {
+    const COEFFICIENTS: [f64; 8] = [
+        676.5203681218851, -1259.1392167224028, 771.3234287776531, -176.6150291621406,
+        12.507343278686905, -0.13857109526572012, 9.984369578019572e-6, 1.5056327351493116e-7,
+    ];
+    let x = x as f64;
+    if x < 0.5 {
+        return (std::f64::consts::PI / ((std::f64::consts::PI * x).sin() * gamma_function((1.0 - x) as f32) as f64)) as f32;
+    }
+    let x = x - 1.0;
+    let mut sum = 0.999_999_999_999_809_9;
+    for (i, c) in COEFFICIENTS.iter().enumerate() {
+        sum += c / (x + i as f64 + 1.0);
+    }
+    let t = x + 7.5;
+    ((2.0 * std::f64::consts::PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * sum) as f32
+}
+
+/// Checks the clutter configuration, the Weibull and K shapes must be positive
+pub fn check_clutter(clutter: &Clutter) -> Result<(), String> {
+    match clutter.statistics {
+        ClutterStatistics::Weibull{shape} | ClutterStatistics::KDistribution{shape} if !(shape > 0.0 && shape.is_finite()) => {
+            Err(format!("clutter shape must be positive, found {}", shape))
+        },
+        _ if clutter.range_resolution.is_nan() || clutter.max_range.is_nan() => Err("clutter ranges must be numbers".to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// Draws a clutter power fluctuation with a mean of one.
+/// Shapes that are not positive fall back to Rayleigh statistics.
+pub fn clutter_fluctuation<R: Rng>(statistics: ClutterStatistics, rng: &mut R) -> f32 {
+    match statistics {
+        ClutterStatistics::Mean => 1.0,
+        // Rayleigh amplitude gives exponentially distributed power
+        ClutterStatistics::Rayleigh => rng.sample(Exp1),
+        ClutterStatistics::Weibull{shape} => {
+            // Weibull amplitude with shape c has Weibull power with shape c / 2
+            let power_shape = shape / 2.0;
+            match Weibull::new(1.0, power_shape) {
+                Ok(weibull) => rng.sample(weibull) / gamma_function(1.0 + 1.0 / power_shape),
+                Err(_) => rng.sample(Exp1),
+            }
+        },
+        ClutterStatistics::KDistribution{shape} => {
+            // Gamma distributed texture modulating exponential speckle
+            let texture: f32 = match Gamma::new(shape, 1.0 / shape) {
+                Ok(gamma) => rng.sample(gamma),
+                Err(_) => 1.0,
+            };
+            let speckle: f32 = rng.sample(Exp1);
+            texture * speckle
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sigma0() {
+        // -15 dB farmland at 30 degrees grazing
+        assert!((constant_gamma_sigma0(TerrainType::Farmland, 30.0) - 0.0158).abs() < 1e-4);
+
+        // Sea clutter rises with sea state and is weaker downwind
+        let calm = git_sea_sigma0(1.0, 1.0, 0.0, 0.03, Polarization::Horizontal);
+        let rough = git_sea_sigma0(5.0, 1.0, 0.0, 0.03, Polarization::Horizontal);
+        let downwind = git_sea_sigma0(5.0, 1.0, 180.0, 0.03, Polarization::Horizontal);
+        assert!(rough > calm);
+        assert!(downwind < rough);
+        // Around 1 degree grazing at X-band the two polarizations are within a few dB
+        let vertical = git_sea_sigma0(3.0, 1.0, 0.0, 0.03, Polarization::Vertical);
+        let horizontal = git_sea_sigma0(3.0, 1.0, 0.0, 0.03, Polarization::Horizontal);
+        assert!((10.0 * (vertical / horizontal).log10()).abs() < 3.0);
+    }
+
+    #[test]
+    fn test_fluctuation() {
+        assert!((gamma_function(5.0) - 24.0).abs() < 1e-3);
+        assert!((gamma_function(0.5) - std::f32::consts::PI.sqrt()).abs() < 1e-4);
+
+        let mut rng = SimulationRng::new(1).rng;
+        let stats = [
+            ClutterStatistics::Mean,
+            ClutterStatistics::Rayleigh,
+            ClutterStatistics::Weibull{shape: 1.5},
+            ClutterStatistics::KDistribution{shape: 0.5},
+        ];
+        for statistics in stats.iter() {
+            let n = 50_000;
+            let mean = (0..n).map(|_| clutter_fluctuation(*statistics, &mut rng)).sum::<f32>() / n as f32;
+            assert!((mean - 1.0).abs() < 0.05, "{:?} mean was {}", statistics, mean);
+        }
+
+        // Bad shapes are rejected up front and fall back to Rayleigh if they get through
+        let bad = ClutterStatistics::Weibull{shape: 0.0};
+        assert!(check_clutter(&Clutter{statistics: bad, ..Clutter::default()}).is_err());
+        assert!(check_clutter(&Clutter{statistics: ClutterStatistics::KDistribution{shape: f32::NAN}, ..Clutter::default()}).is_err());
+        assert!(check_clutter(&Clutter::default()).is_ok());
+        assert!(clutter_fluctuation(bad, &mut rng) >= 0.0);
+        assert!(clutter_fluctuation(ClutterStatistics::KDistribution{shape: -1.0}, &mut rng) >= 0.0);
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+/// Flies a level coordinated turn at `turn_rate` degrees / s for `dt` seconds.
+/// Horizontal speed is kept constant and the vertical velocity is flown straight.
+pub fn coordinated_turn(pos: &mut Position, vel: &mut Velocity, turn_rate: f32, dt: f32) {
+    let omega = turn_rate.to_radians();
+    if omega.abs() < 1e-9 {
+        move_entity(pos, vel, dt);
+        return;
+    }
+    let (sin_wt, cos_wt) = (omega * dt).sin_cos();
+    pos.x += (vel.x * sin_wt - vel.y * (1.0 - cos_wt)) / omega;
+    pos.y += (vel.x * (1.0 - cos_wt) + vel.y * sin_wt) / omega;
+    pos.z += vel.z * dt;
+
+    let (vx, vy) = (vel.x, vel.y);
+    vel.x = vx * cos_wt - vy * sin_wt;
+    vel.y = vx * sin_wt + vy * cos_wt;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinated_turn() {
+        // Quarter circle of radius 100 m at 10 m/s
+        let mut pos = Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0};
+        let mut vel = Velocity{x: 10.0, y: 0.0, z: 1.0};
+        let quarter = (std::f32::consts::PI / 2.0) / 0.1;
+        coordinated_turn(&mut pos, &mut vel, 0.1_f32.to_degrees(), quarter);
+
+        assert!((pos.x - 100.0).abs() < 1e-2);
+        assert!((pos.y - 100.0).abs() < 1e-2);
+        assert!((pos.z - quarter).abs() < 1e-3);
+        assert!(vel.x.abs() < 1e-3);
+        assert!((vel.y - 10.0).abs() < 1e-3);
+    }
+}
@@ -1,10 +1,28 @@
 use super::*;
 
-const C: f32 = 299792458.0;
+/// Speed of light, m/s
+pub const C: f32 = 299792458.0;
 
 pub fn doppler_shift(vel: &Velocity, illum: &Illumination) -> f32 {
     let tot_vel = (vel.x.powi(2) + vel.y.powi(2) + vel.z.powi(2)).sqrt();
-    return (1.0 + (2.0 * (tot_vel / C))) * illum.frequency; 
+    (1.0 + (2.0 * (tot_vel / C))) * illum.frequency
+}
+
+/// Returns the Doppler shift, in Hz, of an echo off a target moving at `vel`.
+/// Kept apart from the carrier, since an f32 at GHz cannot resolve shifts below about a kHz.
+pub fn doppler_offset(vel: &Velocity, illum: &Illumination) -> f32 {
+    let tot_vel = (vel.x.powi(2) + vel.y.powi(2) + vel.z.powi(2)).sqrt();
+    2.0 * (tot_vel / C) * illum.frequency
+}
+
+/// Returns the Doppler shift, in Hz, for a scatterer moving at `radial_velocity` m/s, positive moving away from the radar
+pub fn radial_doppler(frequency: f32, radial_velocity: f32) -> f32 {
+    -2.0 * radial_velocity / C * frequency
+}
+
+/// Shifts a frequency heard by a receiver moving at `radial_velocity` m/s, positive moving away from the emitter
+pub fn one_way_doppler(frequency: f32, radial_velocity: f32) -> f32 {
+    frequency - frequency * radial_velocity / C
 }
 
 #[cfg(test)]
@@ -12,6 +30,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn test_doppler_shift() {
         let vel = Velocity{
             x: 100.0, 
@@ -20,11 +39,39 @@ mod tests {
         };
         let illum = Illumination{
             angle: 100.0, 
+            elevation: 0.0,
             frequency: 10.0, 
+            doppler: 0.0,
             lambda: 100.0, 
             power: 50.0, 
-            rcs: 1.0
+            rcs: 1.0,
+            source: None
         };
         assert_eq!(doppler_shift(&vel, &illum), 10.000009428090416);
     }
+
+    #[test]
+    fn test_doppler_offset() {
+        // 4.78 m/s at 9.4 GHz is a 300 Hz shift, well below the carrier's f32 resolution
+        let vel = Velocity{x: 300.0 * C / (2.0 * 9.4e9), y: 0.0, z: 0.0};
+        let illum = Illumination{
+            angle: 0.0,
+            elevation: 0.0,
+            frequency: 9.4e9,
+            doppler: 0.0,
+            lambda: C / 9.4e9,
+            power: 1.0,
+            rcs: 1.0,
+            source: None
+        };
+        assert!((doppler_offset(&vel, &illum) - 300.0).abs() < 1e-2);
+        assert_eq!(9.4e9_f32 + 300.0, 9.4e9);
+        assert!((radial_doppler(9.4e9, -10.0) - 627.1).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_one_way_doppler() {
+        // Closing at 300 m/s on a 1 GHz emitter
+        assert_eq!(one_way_doppler(1.0e9, -300.0), 1.0e9 + 1000.0);
+    }
 }
\ No newline at end of file
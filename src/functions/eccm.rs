@@ -0,0 +1,44 @@
+use super::*;
+
+/// Fraction of a noise jammer's power landing in a radar's band when the radar hops pulse to pulse
+/// across an agile band. Averaged over pulse frequencies spread evenly across the band.
+pub fn agile_in_band_fraction(noise: &NoiseJamming, center: f32, agile_bandwidth: f32, radar_bandwidth: f32) -> f32 {
+    const HOPS: u32 = 64;
+    if agile_bandwidth <= 0.0 {
+        return in_band_fraction(noise, center, radar_bandwidth);
+    }
+    (0..HOPS)
+        .map(|i| center + agile_bandwidth * ((i as f32 + 0.5) / HOPS as f32 - 0.5))
+        .map(|frequency| in_band_fraction(noise, frequency, radar_bandwidth))
+        .sum::<f32>() / HOPS as f32
+}
+
+/// Total sidelobe jamming power left after a canceller has nulled the strongest jammers,
+/// one per auxiliary channel, each by `ratio`
+pub fn cancel_sidelobe_jamming(mut powers: Vec<f32>, channels: u32, ratio: f32) -> f32 {
+    powers.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    powers.iter().enumerate()
+        .map(|(i, power)| if (i as u32) < channels && ratio > 0.0 { power / ratio } else { *power })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agile_in_band_fraction() {
+        let spot = NoiseJamming{center_frequency: 9.0e9, bandwidth: 10e6};
+        let fixed = in_band_fraction(&spot, 9.0e9, 1e6);
+        // Spread over ten times the jamming band, only a tenth of the pulses are covered
+        let agile = agile_in_band_fraction(&spot, 9.0e9, 100e6, 1e6);
+        assert!((agile / fixed - 0.1).abs() < 0.02);
+        assert_eq!(agile_in_band_fraction(&spot, 9.0e9, 0.0, 1e6), fixed);
+    }
+
+    #[test]
+    fn test_cancel_sidelobe_jamming() {
+        assert_eq!(cancel_sidelobe_jamming(vec![1.0, 100.0, 10.0], 1, 100.0), 12.0);
+        assert_eq!(cancel_sidelobe_jamming(vec![1.0, 100.0], 0, 100.0), 101.0);
+    }
+}
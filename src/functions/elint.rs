@@ -0,0 +1,165 @@
+use super::*;
+use std::collections::HashMap;
+
+// Keeps the pulse difference histogram small for very high PRF emitters
+const MAX_PULSES: usize = 1024;
+// Pulse difference levels counted in the PRI histogram
+const MAX_LEVEL: usize = 4;
+
+/// Expands an intercept into one PDW per pulse over a dwell.
+/// `phase` is the fraction of a PRI before the first pulse, since emitters are not synchronized.
+pub fn generate_pdws(intercept: &Intercept, dwell: f32, phase: f32) -> Vec<Pdw> {
+    let (pri, pulse_width) = match (intercept.pri, intercept.pulse_width) {
+        (Some(pri), Some(pulse_width)) if pri > 0.0 => (pri, pulse_width),
+        // Continuous emissions have no pulses to describe
+        _ => return Vec::new(),
+    };
+    let pulses = ((dwell / pri).round() as usize).min(MAX_PULSES);
+    (0..pulses).map(|k| Pdw{
+        time_of_arrival: intercept.time_of_arrival + (phase + k as f32) * pri,
+        angle: intercept.angle,
+        frequency: intercept.frequency,
+        pulse_width,
+        power: intercept.power
+    }).collect()
+}
+
+/// PRIs that stand out in the histogram of differences between pulse arrival times, shortest first.
+/// A PRI needs at least `threshold` differences within `tolerance` of it.
+pub fn pri_candidates(toas: &[f32], tolerance: f32, threshold: usize) -> Vec<f32> {
+    let mut bins: HashMap<i64, (usize, f32)> = HashMap::new();
+    for (i, toa) in toas.iter().enumerate() {
+        for later in toas.iter().skip(i + 1).take(MAX_LEVEL) {
+            let difference = later - toa;
+            let bin = bins.entry((difference / tolerance).round() as i64).or_insert((0, 0.0));
+            bin.0 += 1;
+            bin.1 += difference;
+        }
+    }
+    let mut candidates: Vec<f32> = bins.values()
+        .filter(|(count, _)| *count >= threshold)
+        .map(|(count, sum)| sum / *count as f32)
+        .filter(|pri| *pri > 0.0)
+        .collect();
+    candidates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    candidates
+}
+
+/// Searches sorted arrival times for a run of pulses spaced by `pri`.
+/// Returns the indices of the first run at least `min_pulses` long.
+pub fn sequence_search(toas: &[f32], pri: f32, tolerance: f32, min_pulses: usize) -> Option<Vec<usize>> {
+    for start in 0..toas.len() {
+        let mut sequence = vec![start];
+        let mut expected = toas[start] + pri;
+        for (i, toa) in toas.iter().enumerate().skip(start + 1) {
+            if (toa - expected).abs() <= tolerance {
+                sequence.push(i);
+                expected = toa + pri;
+            } else if *toa > expected + tolerance {
+                break;
+            }
+        }
+        if sequence.len() >= min_pulses.max(2) {
+            return Some(sequence);
+        }
+    }
+    None
+}
+
+/// Separates interleaved PDWs into pulse trains, each returned with its measured PRI
+pub fn deinterleave(pdws: &[Pdw], tolerance: f32, min_pulses: usize) -> Vec<(f32, Vec<Pdw>)> {
+    let mut remaining = pdws.to_vec();
+    remaining.sort_by(|a, b| a.time_of_arrival.partial_cmp(&b.time_of_arrival).unwrap_or(std::cmp::Ordering::Equal));
+    let mut trains = Vec::new();
+    loop {
+        let toas: Vec<f32> = remaining.iter().map(|pdw| pdw.time_of_arrival).collect();
+        let found = pri_candidates(&toas, tolerance, min_pulses.max(2) - 1).into_iter()
+            .find_map(|pri| sequence_search(&toas, pri, tolerance, min_pulses));
+        let sequence = match found {
+            Some(sequence) => sequence,
+            None => break,
+        };
+        let first = toas[sequence[0]];
+        let last = toas[sequence[sequence.len() - 1]];
+        let pri = (last - first) / (sequence.len() - 1) as f32;
+        // Remove from the back so the earlier indices stay valid
+        let mut train: Vec<Pdw> = sequence.iter().rev().map(|i| remaining.remove(*i)).collect();
+        train.reverse();
+        trains.push((pri, train));
+    }
+    trains
+}
+
+/// Mean of angles in degrees, taking the wrap at 360 into account
+pub fn circular_mean(angles: &[f32]) -> f32 {
+    let (sin_sum, cos_sum) = angles.iter()
+        .map(|a| a.to_radians().sin_cos())
+        .fold((0.0, 0.0), |(s, c), (sin_a, cos_a)| (s + sin_a, c + cos_a));
+    sin_sum.atan2(cos_sum).to_degrees().rem_euclid(360.0)
+}
+
+/// Returns the first library emitter whose parameter ranges cover a measured pulse train
+pub fn identify_emitter(library: &EmitterLibrary, frequency: f32, pri: f32, pulse_width: f32) -> Option<&EmitterEntry> {
+    library.emitters.iter().find(|e| {
+        frequency >= e.min_frequency && frequency <= e.max_frequency
+            && pri >= e.min_pri && pri <= e.max_pri
+            && pulse_width >= e.min_pulse_width && pulse_width <= e.max_pulse_width
+    })
+}
+
+/// Loads an emitter library from a JSON file
+pub fn load_emitter_library<P: AsRef<std::path::Path>>(path: P) -> Result<EmitterLibrary, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&text).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intercept(pri: f32, frequency: f32) -> Intercept {
+        Intercept{
+            angle: 90.0,
+            frequency,
+            doppler: 0.0,
+            pri: Some(pri),
+            pulse_width: Some(1e-6),
+            power: 1e-9,
+            time: 1e-5,
+            time_of_arrival: 1e-5
+        }
+    }
+
+    #[test]
+    fn test_generate_pdws() {
+        let pdws = generate_pdws(&intercept(0.001, 9.4e9), 0.01, 0.5);
+        assert_eq!(pdws.len(), 10);
+        assert!((pdws[1].time_of_arrival - (1e-5 + 0.0015)).abs() < 1e-7);
+        let cw = Intercept{pri: None, ..intercept(0.001, 9.4e9)};
+        assert!(generate_pdws(&cw, 0.01, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_deinterleave() {
+        let mut pdws = generate_pdws(&intercept(0.001, 9.4e9), 0.02, 0.1);
+        pdws.extend(generate_pdws(&intercept(0.0013, 3.0e9), 0.02, 0.7));
+        let trains = deinterleave(&pdws, 2e-6, 5);
+        assert_eq!(trains.len(), 2);
+        let mut pris: Vec<f32> = trains.iter().map(|(pri, _)| *pri).collect();
+        pris.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((pris[0] - 0.001).abs() < 1e-6);
+        assert!((pris[1] - 0.0013).abs() < 1e-6);
+        // Every pulse in a train came from the same emitter
+        for (_, train) in trains.iter() {
+            assert!(train.iter().all(|pdw| pdw.frequency == train[0].frequency));
+        }
+    }
+
+    #[test]
+    fn test_identify_emitter() {
+        let library: EmitterLibrary = serde_json::from_str(&std::fs::read_to_string("src/emitters.json").unwrap()).unwrap();
+        assert_eq!(identify_emitter(&library, 9.4e9, 0.001, 1e-6).map(|e| e.name.as_str()), Some("X-band surveillance"));
+        assert!(identify_emitter(&library, 5.0e9, 0.001, 1e-6).is_none());
+        assert!((circular_mean(&[350.0, 10.0]) % 360.0).abs() < 1e-3);
+    }
+}
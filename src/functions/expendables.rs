@@ -0,0 +1,62 @@
+use super::*;
+
+/// Returns the cross section, in m^2, of a chaff cloud at its current age
+pub fn chaff_rcs(chaff: &Chaff) -> f32 {
+    let bloom = if chaff.bloom_time > 0.0 { 1.0 - (-chaff.age / chaff.bloom_time).exp() } else { 1.0 };
+    let decay = if chaff.decay_time > 0.0 { (-chaff.age / chaff.decay_time).exp() } else { 1.0 };
+    chaff.max_rcs * bloom * decay
+}
+
+/// Returns an RCS table that is the same from every aspect
+pub fn isotropic_rcs(rcs: f32) -> RCS {
+    RCS{angles: vec![0.0], values: vec![rcs], avg_rcs: rcs}
+}
+
+/// Returns the position at the end of a cable trailing `cable_length` meters behind a towing entity.
+/// A stationary tow hangs the cable opposite its heading.
+pub fn towed_position(tow_pos: &Position, tow_vel: &Velocity, cable_length: f32) -> Position {
+    let speed = (tow_vel.x.powi(2) + tow_vel.y.powi(2) + tow_vel.z.powi(2)).sqrt();
+    let (dx, dy, dz) = if speed > 0.0 {
+        (tow_vel.x / speed, tow_vel.y / speed, tow_vel.z / speed)
+    } else {
+        let (sin_h, cos_h) = tow_pos.direction.to_radians().sin_cos();
+        (cos_h, sin_h, 0.0)
+    };
+    Position{
+        x: tow_pos.x - cable_length * dx,
+        y: tow_pos.y - cable_length * dy,
+        z: tow_pos.z - cable_length * dz,
+        direction: tow_pos.direction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chaff_rcs() {
+        let mut chaff = Chaff{
+            max_rcs: 100.0,
+            bloom_time: 1.0,
+            decay_time: 60.0,
+            fall_rate: 1.0,
+            doppler_spread: 10.0,
+            lifetime: 120.0,
+            age: 0.0
+        };
+        assert_eq!(chaff_rcs(&chaff), 0.0);
+        chaff.age = 5.0;
+        let bloomed = chaff_rcs(&chaff);
+        assert!(bloomed > 90.0 && bloomed < 100.0);
+        chaff.age = 60.0;
+        assert!(chaff_rcs(&chaff) < bloomed / 2.0);
+    }
+
+    #[test]
+    fn test_towed_position() {
+        let tow = Position{x: 0.0, y: 0.0, z: 1000.0, direction: 0.0};
+        let vel = Velocity{x: 0.0, y: 200.0, z: 0.0};
+        assert_eq!(towed_position(&tow, &vel, 100.0), Position{x: 0.0, y: -100.0, z: 1000.0, direction: 0.0});
+    }
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+/// Returns how long, in seconds, one pull-off cycle lasts before the jammer drops the gate and starts over
+pub fn pull_off_cycle(jammer: &DeceptionJammer) -> f32 {
+    let walk = |max: f32, rate: f32| if rate > 0.0 { max / rate } else { 0.0 };
+    jammer.hold_time
+        + walk(jammer.max_range_offset, jammer.range_walk_rate)
+            .max(walk(jammer.max_doppler_offset, jammer.doppler_walk_rate))
+}
+
+/// Returns the range (meters) and Doppler (Hz) offsets of the pull-off echo after `engaged_time` seconds
+pub fn pull_off_offsets(jammer: &DeceptionJammer, engaged_time: f32) -> (f32, f32) {
+    let walking = (engaged_time - jammer.hold_time).max(0.0);
+    (
+        (jammer.range_walk_rate * walking).min(jammer.max_range_offset),
+        (jammer.doppler_walk_rate * walking).min(jammer.max_doppler_offset),
+    )
+}
+
+/// Moves a position `offset` meters further away along a direction, in degrees, pointing back at the radar
+pub fn offset_from_radar(pos: &Position, radar_direction: f32, offset: f32) -> Position {
+    let (sin_a, cos_a) = radar_direction.to_radians().sin_cos();
+    Position{
+        x: pos.x - offset * cos_a,
+        y: pos.y - offset * sin_a,
+        z: pos.z,
+        direction: radar_direction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pull_off() {
+        let jammer = DeceptionJammer{
+            effective_rcs: 100.0,
+            hold_time: 2.0,
+            range_walk_rate: 100.0,
+            max_range_offset: 1000.0,
+            doppler_walk_rate: 50.0,
+            max_doppler_offset: 200.0,
+            false_targets: Vec::new(),
+            engaged_time: 0.0
+        };
+        assert_eq!(pull_off_offsets(&jammer, 1.0), (0.0, 0.0));
+        assert_eq!(pull_off_offsets(&jammer, 4.0), (200.0, 100.0));
+        assert_eq!(pull_off_offsets(&jammer, 20.0), (1000.0, 200.0));
+        assert_eq!(pull_off_cycle(&jammer), 12.0);
+
+        let pos = Position{x: 100.0, y: 0.0, z: 10.0, direction: 0.0};
+        assert_eq!(offset_from_radar(&pos, 180.0, 50.0).x, 150.0);
+    }
+}
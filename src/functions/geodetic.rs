@@ -0,0 +1,131 @@
+use super::*;
+
+/// WGS-84 semi-major axis, meters
+pub const WGS84_A: f64 = 6378137.0;
+/// WGS-84 flattening
+pub const WGS84_F: f64 = 1.0 / 298.257223563;
+/// WGS-84 first eccentricity squared
+pub const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F);
+
+/// Converts geodetic coordinates to earth-centered earth-fixed meters
+pub fn geodetic_to_ecef(geo: &GeodeticPosition) -> [f64; 3] {
+    let (sin_lat, cos_lat) = geo.latitude.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = geo.longitude.to_radians().sin_cos();
+    // Prime vertical radius of curvature
+    let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat.powi(2)).sqrt();
+    [
+        (n + geo.altitude) * cos_lat * cos_lon,
+        (n + geo.altitude) * cos_lat * sin_lon,
+        (n * (1.0 - WGS84_E2) + geo.altitude) * sin_lat,
+    ]
+}
+
+/// Converts earth-centered earth-fixed meters to geodetic coordinates (Bowring's method)
+pub fn ecef_to_geodetic(ecef: [f64; 3]) -> GeodeticPosition {
+    let b = WGS84_A * (1.0 - WGS84_F);
+    let ep2 = (WGS84_A.powi(2) - b.powi(2)) / b.powi(2);
+    let p = (ecef[0].powi(2) + ecef[1].powi(2)).sqrt();
+    let theta = (ecef[2] * WGS84_A).atan2(p * b);
+    let (sin_t, cos_t) = theta.sin_cos();
+    let latitude = (ecef[2] + ep2 * b * sin_t.powi(3)).atan2(p - WGS84_E2 * WGS84_A * cos_t.powi(3));
+    let sin_lat = latitude.sin();
+    let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat.powi(2)).sqrt();
+    // Altitude is ill-conditioned through cos(lat) near the poles, so switch formulas there
+    let altitude = if latitude.cos().abs() > 1e-3 {
+        p / latitude.cos() - n
+    } else {
+        ecef[2] / sin_lat - n * (1.0 - WGS84_E2)
+    };
+    GeodeticPosition{
+        latitude: latitude.to_degrees(),
+        longitude: ecef[1].atan2(ecef[0]).to_degrees(),
+        altitude
+    }
+}
+
+/// Expresses an earth-centered earth-fixed point as east / north / up meters from a reference point
+pub fn ecef_to_enu(ecef: [f64; 3], reference: &GeodeticPosition) -> [f64; 3] {
+    let r = geodetic_to_ecef(reference);
+    let d = [ecef[0] - r[0], ecef[1] - r[1], ecef[2] - r[2]];
+    let (sin_lat, cos_lat) = reference.latitude.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = reference.longitude.to_radians().sin_cos();
+    [
+        -sin_lon * d[0] + cos_lon * d[1],
+        -sin_lat * cos_lon * d[0] - sin_lat * sin_lon * d[1] + cos_lat * d[2],
+        cos_lat * cos_lon * d[0] + cos_lat * sin_lon * d[1] + sin_lat * d[2],
+    ]
+}
+
+/// Converts east / north / up meters from a reference point back to earth-centered earth-fixed meters
+pub fn enu_to_ecef(enu: [f64; 3], reference: &GeodeticPosition) -> [f64; 3] {
+    let r = geodetic_to_ecef(reference);
+    let (sin_lat, cos_lat) = reference.latitude.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = reference.longitude.to_radians().sin_cos();
+    [
+        r[0] - sin_lon * enu[0] - sin_lat * cos_lon * enu[1] + cos_lat * cos_lon * enu[2],
+        r[1] + cos_lon * enu[0] - sin_lat * sin_lon * enu[1] + cos_lat * sin_lon * enu[2],
+        r[2] + cos_lat * enu[1] + sin_lat * enu[2],
+    ]
+}
+
+/// Converts geodetic coordinates into the local `Position` frame of the origin
+pub fn geodetic_to_position(geo: &GeodeticPosition, origin: &GeodeticPosition, direction: f32) -> Position {
+    let enu = ecef_to_enu(geodetic_to_ecef(geo), origin);
+    Position{x: enu[0] as f32, y: enu[1] as f32, z: enu[2] as f32, direction}
+}
+
+/// Converts a local `Position` back into geodetic coordinates
+pub fn position_to_geodetic(pos: &Position, origin: &GeodeticPosition) -> GeodeticPosition {
+    ecef_to_geodetic(enu_to_ecef([pos.x as f64, pos.y as f64, pos.z as f64], origin))
+}
+
+/// Returns the slant range (meters), azimuth and elevation (degrees) of a target from an observer on the ellipsoid.
+/// Azimuth follows the `Position::direction` convention, counter-clockwise from east.
+pub fn geodetic_range_az_el(observer: &GeodeticPosition, target: &GeodeticPosition) -> (f64, f64, f64) {
+    let enu = ecef_to_enu(geodetic_to_ecef(target), observer);
+    let horizontal = (enu[0].powi(2) + enu[1].powi(2)).sqrt();
+    let range = (horizontal.powi(2) + enu[2].powi(2)).sqrt();
+    let azimuth = enu[1].atan2(enu[0]).to_degrees().rem_euclid(360.0);
+    let elevation = enu[2].atan2(horizontal).to_degrees();
+    (range, azimuth, elevation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ecef() {
+        let equator = GeodeticPosition{latitude: 0.0, longitude: 0.0, altitude: 0.0};
+        assert_eq!(geodetic_to_ecef(&equator), [WGS84_A, 0.0, 0.0]);
+
+        let geo = GeodeticPosition{latitude: 38.8977, longitude: -77.0365, altitude: 17.0};
+        let back = ecef_to_geodetic(geodetic_to_ecef(&geo));
+        assert!((back.latitude - geo.latitude).abs() < 1e-8);
+        assert!((back.longitude - geo.longitude).abs() < 1e-8);
+        assert!((back.altitude - geo.altitude).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_range_az_el() {
+        // One degree of latitude north along a meridian at the equator is ~110.57 km
+        let radar = GeodeticPosition{latitude: 0.0, longitude: 0.0, altitude: 0.0};
+        let target = GeodeticPosition{latitude: 1.0, longitude: 0.0, altitude: 0.0};
+        let (range, azimuth, elevation) = geodetic_range_az_el(&radar, &target);
+        assert!((range - 110_568.0).abs() < 10.0);
+        assert!((azimuth - 90.0).abs() < 1e-6);
+        // The earth curves away below the horizon
+        assert!((elevation + 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_local_frame() {
+        let origin = GeodeticPosition{latitude: 45.0, longitude: 10.0, altitude: 100.0};
+        let geo = GeodeticPosition{latitude: 45.5, longitude: 10.5, altitude: 3000.0};
+        let pos = geodetic_to_position(&geo, &origin, 0.0);
+        let back = position_to_geodetic(&pos, &origin);
+        assert!((back.latitude - geo.latitude).abs() < 1e-5);
+        assert!((back.longitude - geo.longitude).abs() < 1e-5);
+        assert!((back.altitude - geo.altitude).abs() < 1.0);
+    }
+}
@@ -7,12 +7,18 @@ pub fn incident_angle(emitter: &Position, target: &Position) -> f32 {
     let x_diff = target.x - emitter.x;
     let angle = y_diff.atan2(x_diff) * (180.0 / PI);
     if angle < 0.0 {
-        return 360.0 + angle
+        360.0 + angle
     } else {
-        return angle;
+        angle
     }
 }
 
+/// Returns the elevation, in degrees, of a target as seen from an emitter
+pub fn elevation_angle(emitter: &Position, target: &Position) -> f32 {
+    let horizontal = ((target.x - emitter.x).powi(2) + (target.y - emitter.y).powi(2)).sqrt();
+    (target.z - emitter.z).atan2(horizontal).to_degrees()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,4 +29,12 @@ mod tests {
         let target = Position{x: -50.0, y: -100.0, z: 0.0, direction: 180.0};
         assert_eq!(incident_angle(&emitter, &target), 243.43497);
     }
+
+    #[test]
+    fn test_elevation() {
+        let emitter = Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0};
+        let target = Position{x: 100.0, y: 0.0, z: 100.0, direction: 0.0};
+        assert_eq!(elevation_angle(&emitter, &target), 45.0);
+        assert_eq!(elevation_angle(&target, &emitter), -45.0);
+    }
 }
\ No newline at end of file
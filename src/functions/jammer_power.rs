@@ -0,0 +1,29 @@
+/// Splits a jammer's power between victims in proportion to priority.
+/// Only the `max_beams` highest priorities get a beam, the rest get nothing.
+pub fn allocate_jammer_power(total: f32, priorities: &[f32], max_beams: usize) -> Vec<f32> {
+    let mut order: Vec<usize> = (0..priorities.len()).collect();
+    order.sort_by(|a, b| priorities[*b].partial_cmp(&priorities[*a]).unwrap_or(std::cmp::Ordering::Equal));
+    order.truncate(max_beams);
+
+    let sum: f32 = order.iter().map(|i| priorities[*i].max(0.0)).sum();
+    let mut shares = vec![0.0; priorities.len()];
+    if sum > 0.0 {
+        for i in order {
+            shares[i] = total * priorities[i].max(0.0) / sum;
+        }
+    }
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate_jammer_power() {
+        assert_eq!(allocate_jammer_power(100.0, &[1.0, 3.0], 2), vec![25.0, 75.0]);
+        // Lowest priority loses its beam
+        assert_eq!(allocate_jammer_power(100.0, &[1.0, 3.0, 1.0], 2), vec![25.0, 75.0, 0.0]);
+        assert_eq!(allocate_jammer_power(100.0, &[], 2), Vec::<f32>::new());
+    }
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+/// Returns the distance, in meters, to the radar horizon from a height over an effective earth
+pub fn horizon_distance(height: f32, earth: &EarthModel) -> f32 {
+    (2.0 * earth.k_factor * earth.radius * height.max(0.0)).sqrt()
+}
+
+// meters the effective earth may rise into a path between antennas on the ground before it blocks it
+const GROUND_CLEARANCE: f32 = 1.0;
+
+/// Returns true if two positions can see each other over the curved earth.
+/// Heights are taken from `Position::z`, with antennas at or below zero sitting on the surface,
+/// and refraction from the earth model's k-factor.
+pub fn line_of_sight(pos1: &Position, pos2: &Position, earth: &EarthModel) -> bool {
+    let ground_range = ((pos1.x - pos2.x).powi(2) + (pos1.y - pos2.y).powi(2)).sqrt();
+    let (height1, height2) = (pos1.z.max(0.0), pos2.z.max(0.0));
+    if ground_range <= horizon_distance(height1, earth) + horizon_distance(height2, earth) {
+        return true;
+    }
+    // An antenna on the ground has no horizon of its own, so it is only blocked
+    // once the effective earth bulges into the path by more than the clearance
+    let bulge = ground_range.powi(2) / (8.0 * earth.k_factor * earth.radius);
+    height1.min(height2) == 0.0 && bulge <= GROUND_CLEARANCE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_horizon() {
+        let earth = EarthModel::default();
+        // The classic 4.12 * sqrt(h) km rule of thumb
+        assert!((horizon_distance(100.0, &earth) - 41_210.0).abs() < 50.0);
+
+        let radar = Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0};
+        let near = Position{x: 20_000.0, y: 0.0, z: 10.0, direction: 0.0};
+        let far = Position{x: 400_000.0, y: 0.0, z: 10.0, direction: 0.0};
+        let high = Position{x: 400_000.0, y: 0.0, z: 10_000.0, direction: 0.0};
+        assert!(line_of_sight(&radar, &near, &earth));
+        assert!(!line_of_sight(&radar, &far, &earth));
+        assert!(line_of_sight(&radar, &high, &earth));
+
+        // Antennas on the ground close by still see each other
+        let ground = Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0};
+        let neighbour = Position{x: 100.0, y: 100.0, z: 0.0, direction: 0.0};
+        assert!(line_of_sight(&ground, &neighbour, &earth));
+        // but not past the first few kilometers, and a buried antenna is no worse off than one on the surface
+        let distant = Position{x: 20_000.0, y: 0.0, z: 0.0, direction: 0.0};
+        let buried = Position{x: 100.0, y: 100.0, z: -5.0, direction: 0.0};
+        assert!(!line_of_sight(&ground, &distant, &earth));
+        assert!(line_of_sight(&ground, &buried, &earth));
+    }
+}
@@ -1,9 +1,20 @@
 use super::*;
 
-pub fn move_entity(pos: &mut Position, vel: &Velocity) {
-    pos.x += vel.x;
-    pos.y += vel.y;
-    pos.z += vel.z;
+/// Moves a position along a velocity for `dt` seconds
+pub fn move_entity(pos: &mut Position, vel: &Velocity, dt: f32) {
+    pos.x += vel.x * dt;
+    pos.y += vel.y * dt;
+    pos.z += vel.z * dt;
+}
+
+/// Moves a position and velocity under a constant acceleration for `dt` seconds
+pub fn accelerate_entity(pos: &mut Position, vel: &mut Velocity, acc: &Acceleration, dt: f32) {
+    pos.x += vel.x * dt + 0.5 * acc.x * dt.powi(2);
+    pos.y += vel.y * dt + 0.5 * acc.y * dt.powi(2);
+    pos.z += vel.z * dt + 0.5 * acc.z * dt.powi(2);
+    vel.x += acc.x * dt;
+    vel.y += acc.y * dt;
+    vel.z += acc.z * dt;
 }
 
 // #[cfg(test)]
@@ -14,7 +25,7 @@ pub fn move_entity(pos: &mut Position, vel: &Velocity) {
 //     fn test_move() {
 //         let mut pos = Position{x: 10.0, y: 0.0, z: 1.0, direction: 0.0};
 //         let vel = Velocity{x: 100.0, y: 100.0, z: 0.0};
-//         move_entity(&mut pos, &vel);
+//         move_entity(&mut pos, &vel, 1.0);
 //         assert_eq!(pos, Position{x: 110.0, y: 100.0, z: 1.0, direction: 0.0});
 //     }
 // }
\ No newline at end of file
@@ -0,0 +1,108 @@
+use super::*;
+
+// Complex numbers as (real, imaginary)
+type Complex = (f32, f32);
+
+fn c_mul(a: Complex, b: Complex) -> Complex {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+fn c_div(a: Complex, b: Complex) -> Complex {
+    let denom = b.0.powi(2) + b.1.powi(2);
+    ((a.0 * b.0 + a.1 * b.1) / denom, (a.1 * b.0 - a.0 * b.1) / denom)
+}
+
+fn c_sqrt(a: Complex) -> Complex {
+    let r = (a.0.powi(2) + a.1.powi(2)).sqrt().sqrt();
+    let theta = a.1.atan2(a.0) / 2.0;
+    (r * theta.cos(), r * theta.sin())
+}
+
+/// Returns the relative permittivity and conductivity (S/m) of a surface
+pub fn surface_electrical_properties(kind: SurfaceKind) -> (f32, f32) {
+    match kind {
+        SurfaceKind::Sea => (80.0, 4.0),
+        SurfaceKind::Land => (15.0, 0.005),
+        SurfaceKind::None => (1.0, 0.0),
+    }
+}
+
+/// Returns the complex Fresnel reflection coefficient of a surface for a grazing angle in degrees,
+/// reduced by the rough surface factor exp(-2 (2 pi h sin(psi) / lambda)^2)
+pub fn reflection_coefficient(surface: &Surface, grazing: f32, wavelength: f32) -> Complex {
+    if surface.kind == SurfaceKind::None {
+        return (0.0, 0.0);
+    }
+    let (eps_r, sigma) = surface_electrical_properties(surface.kind);
+    let eps: Complex = (eps_r, -60.0 * wavelength * sigma);
+    let (sin_psi, cos_psi) = grazing.to_radians().sin_cos();
+    let root = c_sqrt((eps.0 - cos_psi.powi(2), eps.1));
+    let gamma = match surface.polarization {
+        Polarization::Horizontal => c_div((sin_psi - root.0, -root.1), (sin_psi + root.0, root.1)),
+        Polarization::Vertical => {
+            let eps_sin = (eps.0 * sin_psi, eps.1 * sin_psi);
+            c_div((eps_sin.0 - root.0, eps_sin.1 - root.1), (eps_sin.0 + root.0, eps_sin.1 + root.1))
+        }
+    };
+    let rough = (-2.0 * (2.0 * std::f32::consts::PI * surface.roughness * sin_psi / wavelength).powi(2)).exp();
+    (gamma.0 * rough, gamma.1 * rough)
+}
+
+/// Returns the one way flat earth two-ray propagation factor F (amplitude) between two positions.
+/// Heights come from `Position::z`; the power on a leg scales with F^2, so F^4 for a radar echo.
+pub fn propagation_factor(pos1: &Position, pos2: &Position, wavelength: f32, surface: &Surface) -> f32 {
+    if surface.kind == SurfaceKind::None || pos1.z <= 0.0 || pos2.z <= 0.0 {
+        return 1.0;
+    }
+    let ground_range = ((pos1.x - pos2.x).powi(2) + (pos1.y - pos2.y).powi(2)).sqrt();
+    let grazing = (pos1.z + pos2.z).atan2(ground_range).to_degrees();
+    if grazing > surface.max_grazing_angle {
+        return 1.0;
+    }
+    let direct = (ground_range.powi(2) + (pos2.z - pos1.z).powi(2)).sqrt();
+    let reflected = (ground_range.powi(2) + (pos2.z + pos1.z).powi(2)).sqrt();
+    // Written as 4 h1 h2 / (Rr + Rd) rather than Rr - Rd, which cancels badly in f32 at long range
+    let path_difference = 4.0 * pos1.z * pos2.z / (reflected + direct);
+    let delta = 2.0 * std::f32::consts::PI * path_difference / wavelength;
+
+    let gamma = reflection_coefficient(surface, grazing, wavelength);
+    let indirect = c_mul(gamma, (delta.cos(), -delta.sin()));
+    ((1.0 + indirect.0).powi(2) + indirect.1.powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reflection_coefficient() {
+        let sea = Surface{kind: SurfaceKind::Sea, ..Surface::default()};
+        // Near grazing, a smooth sea reflects horizontal polarization almost perfectly with a phase flip
+        let gamma = reflection_coefficient(&sea, 0.1, 0.03);
+        assert!((gamma.0 + 1.0).abs() < 0.01);
+
+        // Vertical polarization dips through the pseudo-Brewster angle
+        let vertical = Surface{polarization: Polarization::Vertical, ..sea};
+        let gamma = reflection_coefficient(&vertical, 6.0, 0.03);
+        assert!((gamma.0.powi(2) + gamma.1.powi(2)).sqrt() < 0.3);
+
+        // Rough sea scatters the specular ray away
+        let rough = Surface{roughness: 1.0, ..sea};
+        let gamma = reflection_coefficient(&rough, 5.0, 0.03);
+        assert!((gamma.0.powi(2) + gamma.1.powi(2)).sqrt() < 0.01);
+    }
+
+    #[test]
+    fn test_lobing() {
+        let sea = Surface{kind: SurfaceKind::Sea, ..Surface::default()};
+        let wavelength = 0.03;
+        let radar = Position{x: 0.0, y: 0.0, z: 20.0, direction: 0.0};
+        // Path difference is ~2 h1 h2 / d, half a wavelength puts the target on the first lobe peak
+        let d = 2.0 * 20.0 * 15.0 / (wavelength / 2.0);
+        let peak = Position{x: d, y: 0.0, z: 15.0, direction: 0.0};
+        let null = Position{x: d, y: 0.0, z: 30.0, direction: 0.0};
+        assert!(propagation_factor(&radar, &peak, wavelength, &sea) > 1.9);
+        assert!(propagation_factor(&radar, &null, wavelength, &sea) < 0.1);
+        assert_eq!(propagation_factor(&radar, &peak, wavelength, &Surface::default()), 1.0);
+    }
+}
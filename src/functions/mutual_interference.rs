@@ -0,0 +1,79 @@
+use super::*;
+
+/// Returns the fraction of an interfering radar's power that lands in the victim's receiver band.
+/// Bandwidths come from the waveforms. When either is unknown, all of the power gets through if the carriers fall inside the known band.
+pub fn interference_fraction(interferer_frequency: f32, interferer: Option<&Waveform>, victim_frequency: f32, victim: Option<&Waveform>) -> f32 {
+    let interferer_bandwidth = radar_bandwidth(interferer);
+    let victim_bandwidth = radar_bandwidth(victim);
+    if interferer_bandwidth <= 0.0 || victim_bandwidth <= 0.0 {
+        let inside = (interferer_frequency - victim_frequency).abs() <= interferer_bandwidth.max(victim_bandwidth) / 2.0;
+        return if inside { 1.0 } else { 0.0 };
+    }
+    band_overlap(interferer_frequency, interferer_bandwidth, victim_frequency, victim_bandwidth) / interferer_bandwidth
+}
+
+/// Fraction of the time a radar is transmitting, one for CW or unknown waveforms
+pub fn duty_cycle(waveform: Option<&Waveform>) -> f32 {
+    waveform.map_or(1.0, |wf| (wf.pulse_width * wf.prf).min(1.0))
+}
+
+/// Apparent ranges, in meters, at which an interfering pulse train shows up over one victim dwell,
+/// along with the number of victim pulses each range bin was hit in.
+/// The interferer's pulses leave at multiples of its PRI and arrive `delay` seconds later,
+/// the victim's dwell starts at `start` seconds. Asynchronous trains land in a different bin every pulse,
+/// only a train locked to the victim's PRI keeps hitting the same bin and integrates like a real echo.
+pub fn interference_ranges(interferer_pri: f32, victim_pri: f32, delay: f32, start: f32, pulses: u32, range_bin: f32) -> Vec<(f32, u32)> {
+    let mut bins: Vec<(f32, u32)> = Vec::new();
+    if interferer_pri <= 0.0 || victim_pri <= 0.0 {
+        return bins;
+    }
+    // Pulse times are taken modulo the PRIs, f32 cannot hold microseconds after a few minutes of simulation
+    let (interferer_pri, victim_pri, delay) = (interferer_pri as f64, victim_pri as f64, delay as f64);
+    for pulse in 0..pulses {
+        let window = start as f64 + pulse as f64 * victim_pri;
+        let mut arrival = window + (delay - window).rem_euclid(interferer_pri);
+        while arrival < window + victim_pri {
+            let range = (C as f64 / 2.0 * (arrival - window)) as f32;
+            let range = if range_bin > 0.0 { (range / range_bin).floor() * range_bin } else { range };
+            match bins.iter_mut().find(|(bin, _)| *bin == range) {
+                Some((_, hits)) => *hits += 1,
+                None => bins.push((range, 1)),
+            }
+            arrival += interferer_pri;
+        }
+    }
+    bins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interference_fraction() {
+        let waveform = |pulse_width: f32| Waveform{id: 0, prf: 1000.0, pulse_width, pulses: 10, stagger: Vec::new()};
+        // A 1 MHz interferer half inside a 1 MHz victim band
+        let fraction = interference_fraction(1.0005e9, Some(&waveform(1e-6)), 1.0e9, Some(&waveform(1e-6)));
+        assert!((fraction - 0.5).abs() < 1e-3);
+        assert_eq!(interference_fraction(1.1e9, Some(&waveform(1e-6)), 1.0e9, Some(&waveform(1e-6))), 0.0);
+        assert_eq!(interference_fraction(1.0e9, None, 1.0e9, Some(&waveform(1e-6))), 1.0);
+        assert_eq!(interference_fraction(1.0e9, Some(&waveform(1e-6)), 1.0e9, None), 1.0);
+        assert!((duty_cycle(Some(&waveform(1e-6))) - 1e-3).abs() < 1e-9);
+        assert_eq!(duty_cycle(None), 1.0);
+    }
+
+    #[test]
+    fn test_interference_ranges() {
+        // Locked to the victim's PRI, every pulse lands 15 km out
+        let synchronous = interference_ranges(1e-3, 1e-3, 1e-4, 0.0, 10, 150.0);
+        assert_eq!(synchronous.len(), 1);
+        assert!((synchronous[0].0 - 15_000.0).abs() <= 150.0);
+        assert_eq!(synchronous[0].1, 10);
+
+        // A slightly different PRI walks through the range bins
+        let asynchronous = interference_ranges(1.1e-3, 1e-3, 1e-4, 0.0, 10, 150.0);
+        assert!(asynchronous.len() > 5);
+        assert!(asynchronous.iter().all(|(range, hits)| *hits < 10 && *range < C * 1e-3 / 2.0));
+        assert!(interference_ranges(0.0, 1e-3, 0.0, 0.0, 10, 150.0).is_empty());
+    }
+}
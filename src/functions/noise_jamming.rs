@@ -0,0 +1,68 @@
+use super::*;
+
+/// Returns the width, in Hz, of the overlap between two bands given by center frequency and bandwidth
+pub fn band_overlap(center1: f32, bandwidth1: f32, center2: f32, bandwidth2: f32) -> f32 {
+    // Work relative to the first band so narrow bands keep their precision at GHz frequencies
+    let offset = center2 - center1;
+    let low = (-bandwidth1 / 2.0).max(offset - bandwidth2 / 2.0);
+    let high = (bandwidth1 / 2.0).min(offset + bandwidth2 / 2.0);
+    (high - low).max(0.0)
+}
+
+/// Returns the fraction of a noise jammer's power that lands in a radar's receiver band.
+/// A radar bandwidth of zero (unknown) takes all of the noise if its frequency is inside the jamming band.
+pub fn in_band_fraction(noise: &NoiseJamming, radar_frequency: f32, radar_bandwidth: f32) -> f32 {
+    if noise.bandwidth <= 0.0 {
+        return 0.0;
+    }
+    if radar_bandwidth <= 0.0 {
+        let inside = (radar_frequency - noise.center_frequency).abs() <= noise.bandwidth / 2.0;
+        return if inside { 1.0 } else { 0.0 };
+    }
+    band_overlap(noise.center_frequency, noise.bandwidth, radar_frequency, radar_bandwidth) / noise.bandwidth
+}
+
+/// Returns the matched filter bandwidth, in Hz, of a radar with a waveform, or zero when unknown
+pub fn radar_bandwidth(waveform: Option<&Waveform>) -> f32 {
+    waveform.map_or(0.0, |wf| 1.0 / wf.pulse_width)
+}
+
+/// Jamming to signal ratio of a self-screening jammer at `range`.
+/// `radar_erp` and `jammer_erp` are power times gain in Watts, `fraction` is the jammer's in-band fraction.
+pub fn self_screening_js(radar_erp: f32, jammer_erp: f32, fraction: f32, rcs: f32, range: f32) -> f32 {
+    // J = Pj Gj f Ae / (4 pi R^2), S = Pt Gt rcs Ae / ((4 pi)^2 R^4)
+    4.0 * std::f32::consts::PI * jammer_erp * fraction * range.powi(2) / (radar_erp * rcs)
+}
+
+/// Range, in meters, at which a self-screening jammer's J/S falls to one and the echo burns through
+pub fn burn_through_range(radar_erp: f32, jammer_erp: f32, fraction: f32, rcs: f32) -> f32 {
+    if fraction <= 0.0 {
+        return f32::INFINITY;
+    }
+    (radar_erp * rcs / (4.0 * std::f32::consts::PI * jammer_erp * fraction)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_band_overlap() {
+        let barrage = NoiseJamming{center_frequency: 9.5e9, bandwidth: 1e9};
+        assert_eq!(in_band_fraction(&barrage, 9.4e9, 1e6), 1e6 / 1e9);
+        assert_eq!(in_band_fraction(&barrage, 8.0e9, 1e6), 0.0);
+        // Half of the radar's band sticks out of the top of the jamming band (f32 resolves ~1 kHz at 10 GHz)
+        assert!((band_overlap(9.5e9, 1e9, 10.0e9, 2e6) - 1e6).abs() < 1e3);
+        assert_eq!(in_band_fraction(&barrage, 9.4e9, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_burn_through() {
+        let (radar_erp, jammer_erp, rcs) = (1e5 * 1000.0, 100.0 * 10.0, 10.0);
+        let fraction = 0.01;
+        let burn_through = burn_through_range(radar_erp, jammer_erp, fraction, rcs);
+        assert!((self_screening_js(radar_erp, jammer_erp, fraction, rcs, burn_through) - 1.0).abs() < 1e-3);
+        // J/S grows with the square of range for a self-screening jammer
+        assert!((self_screening_js(radar_erp, jammer_erp, fraction, rcs, 2.0 * burn_through) - 4.0).abs() < 1e-2);
+    }
+}
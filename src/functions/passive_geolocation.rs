@@ -0,0 +1,193 @@
+use super::*;
+
+// Solved in f64, ranges of hundreds of kilometres leave too little precision in f32 for nanosecond TDOA
+fn range_to(sensor: [f32; 3], x: f64, y: f64, z: f64) -> f64 {
+    ((x - sensor[0] as f64).powi(2) + (y - sensor[1] as f64).powi(2) + (z - sensor[2] as f64).powi(2)).sqrt()
+}
+
+// Doppler heard by a moving sensor from a stationary emitter, over the emitter frequency
+fn relative_doppler(sensor: [f32; 3], velocity: [f32; 3], x: f64, y: f64, z: f64) -> f64 {
+    let range = range_to(sensor, x, y, z);
+    let away = (velocity[0] as f64 * (sensor[0] as f64 - x) + velocity[1] as f64 * (sensor[1] as f64 - y)
+        + velocity[2] as f64 * (sensor[2] as f64 - z)) / range;
+    -away / C as f64
+}
+
+/// Residual of a measurement at an emitter position, in standard deviations
+pub fn geo_residual(measurement: &GeoMeasurement, x: f64, y: f64, z: f64) -> f64 {
+    match *measurement {
+        GeoMeasurement::Bearing{sensor, bearing, sigma} => {
+            let predicted = (y - sensor[1] as f64).atan2(x - sensor[0] as f64).to_degrees();
+            let error = (bearing as f64 - predicted + 540.0).rem_euclid(360.0) - 180.0;
+            error / (sigma as f64).max(1e-3)
+        },
+        GeoMeasurement::Tdoa{sensor, reference, difference, sigma} => {
+            let predicted = (range_to(sensor, x, y, z) - range_to(reference, x, y, z)) / C as f64;
+            (difference as f64 - predicted) / (sigma as f64).max(1e-12)
+        },
+        GeoMeasurement::Fdoa{sensor, sensor_velocity, reference, reference_velocity, frequency, difference, sigma} => {
+            let predicted = frequency as f64 * (relative_doppler(sensor, sensor_velocity, x, y, z)
+                - relative_doppler(reference, reference_velocity, x, y, z));
+            (difference as f64 - predicted) / (sigma as f64).max(1e-6)
+        },
+    }
+}
+
+/// Weighted least squares emitter position from passive measurements, starting at `initial`.
+/// Returns the position in the horizontal plane at `altitude` and its 2x2 covariance.
+pub fn locate_emitter(measurements: &[GeoMeasurement], initial: (f32, f32), altitude: f32) -> Option<(f32, f32, [[f32; 2]; 2])> {
+    if measurements.len() < 2 {
+        return None;
+    }
+    let z = altitude as f64;
+    let cost = |x: f64, y: f64| measurements.iter().map(|m| geo_residual(m, x, y, z).powi(2)).sum::<f64>();
+    let jacobian = |x: f64, y: f64| -> Vec<(f64, f64, f64)> {
+        // Central differences, a metre either side
+        measurements.iter().map(|m| {
+            let r = geo_residual(m, x, y, z);
+            let dx = (geo_residual(m, x + 1.0, y, z) - geo_residual(m, x - 1.0, y, z)) / 2.0;
+            let dy = (geo_residual(m, x, y + 1.0, z) - geo_residual(m, x, y - 1.0, z)) / 2.0;
+            (r, dx, dy)
+        }).collect()
+    };
+    let normal = |rows: &[(f64, f64, f64)]| rows.iter().fold([0.0; 5], |n, (r, dx, dy)| {
+        [n[0] + dx * dx, n[1] + dx * dy, n[2] + dy * dy, n[3] + dx * r, n[4] + dy * r]
+    });
+
+    // Levenberg-Marquardt
+    let (mut x, mut y) = (initial.0 as f64, initial.1 as f64);
+    let mut current = cost(x, y);
+    let mut damping = 1e-3;
+    for _ in 0..100 {
+        let n = normal(&jacobian(x, y));
+        let (a, b, c) = (n[0] * (1.0 + damping), n[1], n[2] * (1.0 + damping));
+        let det = a * c - b * b;
+        if det.abs() < 1e-30 {
+            break;
+        }
+        let step_x = -(c * n[3] - b * n[4]) / det;
+        let step_y = -(a * n[4] - b * n[3]) / det;
+        let trial = cost(x + step_x, y + step_y);
+        if trial < current {
+            x += step_x;
+            y += step_y;
+            damping /= 10.0;
+            let converged = current - trial < 1e-12 * current.max(1.0) && step_x.hypot(step_y) < 1e-3;
+            current = trial;
+            if converged {
+                break;
+            }
+        } else {
+            damping *= 10.0;
+        }
+    }
+
+    let n = normal(&jacobian(x, y));
+    let det = n[0] * n[2] - n[1] * n[1];
+    if det <= 0.0 || !x.is_finite() || !y.is_finite() {
+        return None;
+    }
+    let covariance = [
+        [(n[2] / det) as f32, (-n[1] / det) as f32],
+        [(-n[1] / det) as f32, (n[0] / det) as f32],
+    ];
+    Some((x as f32, y as f32, covariance))
+}
+
+/// Point where two bearing lines cross, or None when they are parallel or cross behind a sensor
+pub fn bearing_intersection(p1: (f32, f32), bearing1: f32, p2: (f32, f32), bearing2: f32) -> Option<(f32, f32)> {
+    let (s1, c1) = bearing1.to_radians().sin_cos();
+    let (s2, c2) = bearing2.to_radians().sin_cos();
+    let det = c2 * s1 - c1 * s2;
+    if det.abs() < 1e-6 {
+        return None;
+    }
+    // p1 + t1 d1 = p2 + t2 d2
+    let (dx, dy) = (p2.0 - p1.0, p2.1 - p1.1);
+    let t1 = (dy * c2 - dx * s2) / det;
+    let t2 = (dy * c1 - dx * s1) / det;
+    if t1 < 0.0 || t2 < 0.0 {
+        return None;
+    }
+    Some((p1.0 + t1 * c1, p1.1 + t1 * s1))
+}
+
+/// Semi-major axis, semi-minor axis and major axis direction, in degrees, of the 95% error ellipse of a covariance
+pub fn error_ellipse(covariance: [[f32; 2]; 2]) -> (f32, f32, f32) {
+    // Chi-squared with two degrees of freedom at 95%
+    let scale = 5.991_f32.sqrt();
+    let (a, b, c) = (covariance[0][0], covariance[0][1], covariance[1][1]);
+    let mean = (a + c) / 2.0;
+    let spread = (((a - c) / 2.0).powi(2) + b * b).sqrt();
+    let orientation = (0.5 * (2.0 * b).atan2(a - c)).to_degrees().rem_euclid(180.0);
+    (scale * (mean + spread).sqrt(), scale * (mean - spread).max(0.0).sqrt(), orientation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMITTER: (f32, f32) = (10000.0, 20000.0);
+
+    fn bearing_from(sensor: [f32; 3]) -> GeoMeasurement {
+        let bearing = (EMITTER.1 - sensor[1]).atan2(EMITTER.0 - sensor[0]).to_degrees();
+        GeoMeasurement::Bearing{sensor, bearing, sigma: 1.0}
+    }
+
+    fn tdoa(sensor: [f32; 3], reference: [f32; 3]) -> GeoMeasurement {
+        let range = |s: [f32; 3]| range_to(s, EMITTER.0 as f64, EMITTER.1 as f64, 0.0);
+        let difference = ((range(sensor) - range(reference)) / C as f64) as f32;
+        GeoMeasurement::Tdoa{sensor, reference, difference, sigma: 50e-9}
+    }
+
+    #[test]
+    fn test_triangulation() {
+        let measurements = [bearing_from([0.0, 0.0, 0.0]), bearing_from([30000.0, 0.0, 0.0])];
+        let (x, y, covariance) = locate_emitter(&measurements, (15000.0, 15000.0), 0.0).unwrap();
+        assert!((x - EMITTER.0).abs() < 1.0 && (y - EMITTER.1).abs() < 1.0);
+        let (major, minor, _) = error_ellipse(covariance);
+        // A degree of bearing error at over 20 km is hundreds of meters
+        assert!(major > minor && minor > 100.0 && major < 5000.0);
+    }
+
+    #[test]
+    fn test_tdoa() {
+        let reference = [0.0, 0.0, 0.0];
+        let measurements = [
+            tdoa([40000.0, 0.0, 0.0], reference),
+            tdoa([0.0, 40000.0, 0.0], reference),
+            tdoa([40000.0, 40000.0, 0.0], reference),
+        ];
+        let (x, y, covariance) = locate_emitter(&measurements, (20000.0, 20000.0), 0.0).unwrap();
+        assert!((x - EMITTER.0).abs() < 1.0 && (y - EMITTER.1).abs() < 1.0);
+        // Fifty nanoseconds is about fifteen meters of range difference
+        assert!(error_ellipse(covariance).0 < 200.0);
+    }
+
+    #[test]
+    fn test_fdoa() {
+        let (sensor, sensor_velocity) = ([0.0, 0.0, 5000.0], [200.0, 0.0, 0.0]);
+        let (reference, reference_velocity) = ([0.0, 5000.0, 5000.0], [200.0, 0.0, 0.0]);
+        let doppler = |s, v| relative_doppler(s, v, EMITTER.0 as f64, EMITTER.1 as f64, 0.0);
+        let difference = (1.0e9 * (doppler(sensor, sensor_velocity) - doppler(reference, reference_velocity))) as f32;
+        let fdoa = GeoMeasurement::Fdoa{sensor, sensor_velocity, reference, reference_velocity, frequency: 1.0e9, difference, sigma: 1.0};
+        assert!(geo_residual(&fdoa, EMITTER.0 as f64, EMITTER.1 as f64, 0.0).abs() < 1e-3);
+        assert!(geo_residual(&fdoa, 0.0, 20000.0, 0.0).abs() > 1.0);
+    }
+
+    #[test]
+    fn test_bearing_intersection() {
+        let crossing = bearing_intersection((0.0, 0.0), 45.0, (100.0, 0.0), 135.0).unwrap();
+        assert!((crossing.0 - 50.0).abs() < 1e-3 && (crossing.1 - 50.0).abs() < 1e-3);
+        assert!(bearing_intersection((0.0, 0.0), 0.0, (0.0, 100.0), 0.0).is_none());
+        assert!(bearing_intersection((0.0, 0.0), 225.0, (100.0, 0.0), 315.0).is_none());
+    }
+
+    #[test]
+    fn test_error_ellipse() {
+        let (major, minor, orientation) = error_ellipse([[4.0, 0.0], [0.0, 1.0]]);
+        assert!((major - 2.0 * 5.991_f32.sqrt()).abs() < 1e-4);
+        assert!((minor - 5.991_f32.sqrt()).abs() < 1e-4);
+        assert_eq!(orientation, 0.0);
+    }
+}
@@ -0,0 +1,209 @@
+use super::*;
+
+/// Binomial canceller weights for an MTI filter
+pub fn mti_weights(filter: MtiFilter) -> Vec<f32> {
+    match filter {
+        MtiFilter::None => vec![1.0],
+        MtiFilter::TwoPulse => vec![1.0, -1.0],
+        MtiFilter::ThreePulse => vec![1.0, -2.0, 1.0],
+    }
+}
+
+/// Returns the complex weights, as (real, imaginary), of every output filter of a signal processor.
+/// A filter bank follows the MTI canceller and drops its zero Doppler filter, where the clutter sits.
+pub fn processor_weights(processor: &SignalProcessor) -> Vec<Vec<(f32, f32)>> {
+    let mti = mti_weights(processor.mti);
+    let n = processor.doppler_filters as usize;
+    if n == 0 {
+        return vec![mti.iter().map(|&w| (w, 0.0)).collect()];
+    }
+    let first_bin = if n > 1 { 1 } else { 0 };
+    (first_bin..n).map(|k| {
+        let mut weights = vec![(0.0, 0.0); mti.len() + n - 1];
+        for (i, m) in mti.iter().enumerate() {
+            for j in 0..n {
+                let phase = -2.0 * std::f32::consts::PI * (k * j) as f32 / n as f32;
+                weights[i + j].0 += m * phase.cos();
+                weights[i + j].1 += m * phase.sin();
+            }
+        }
+        weights
+    }).collect()
+}
+
+/// Checks a waveform can be processed, the PRF must be positive and any stagger ratios positive
+pub fn check_waveform(waveform: &Waveform) -> Result<(), String> {
+    if !(waveform.prf > 0.0 && waveform.prf.is_finite() && waveform.pulse_width >= 0.0) {
+        return Err(format!("waveform {} needs a positive PRF and pulse width", waveform.id));
+    }
+    if !waveform.stagger.iter().all(|ratio| *ratio > 0.0 && ratio.is_finite()) {
+        return Err(format!("waveform {} stagger ratios must all be positive", waveform.id));
+    }
+    Ok(())
+}
+
+// Stagger ratios that cannot be used fall back to a constant PRF
+fn stagger(waveform: &Waveform) -> &[f32] {
+    if waveform.stagger.iter().all(|ratio| *ratio > 0.0 && ratio.is_finite()) {
+        &waveform.stagger
+    } else {
+        &[]
+    }
+}
+
+/// Returns the transmit time, in seconds, of `count` consecutive pulses starting at pulse `first`,
+/// following any PRF stagger
+pub fn pulse_times(waveform: &Waveform, first: usize, count: usize) -> Vec<f32> {
+    let pri = 1.0 / waveform.prf;
+    let stagger = stagger(waveform);
+    let mean_ratio = if stagger.is_empty() {
+        1.0
+    } else {
+        stagger.iter().sum::<f32>() / stagger.len() as f32
+    };
+    let mut times = vec![0.0; count];
+    for i in 1..count {
+        let ratio = if stagger.is_empty() { 1.0 } else { stagger[(first + i - 1) % stagger.len()] };
+        times[i] = times[i - 1] + pri * ratio / mean_ratio;
+    }
+    times
+}
+
+// Power gain of one filter at a Doppler frequency, normalized so white noise has unity gain
+fn filter_gain(weights: &[(f32, f32)], times: &[f32], doppler: f32) -> f32 {
+    let (mut re, mut im, mut norm) = (0.0, 0.0, 0.0);
+    for (w, t) in weights.iter().zip(times.iter()) {
+        let (sin_p, cos_p) = (2.0 * std::f32::consts::PI * doppler * t).sin_cos();
+        re += w.0 * cos_p - w.1 * sin_p;
+        im += w.0 * sin_p + w.1 * cos_p;
+        norm += w.0.powi(2) + w.1.powi(2);
+    }
+    (re.powi(2) + im.powi(2)) / norm
+}
+
+// Each stagger phase gives the filter a different set of pulse spacings
+fn stagger_phases(waveform: &Waveform) -> usize {
+    stagger(waveform).len().max(1)
+}
+
+/// Returns the signal processor's power gain for a return at `doppler` Hz, taking the best filter in the bank.
+/// With a staggered PRF the gain is averaged as the filter slides along the pulse train.
+pub fn processing_gain(processor: &SignalProcessor, waveform: &Waveform, doppler: f32) -> f32 {
+    let filters = processor_weights(processor);
+    let phases = stagger_phases(waveform);
+    let mut total = 0.0;
+    for phase in 0..phases {
+        let times = pulse_times(waveform, phase, filters[0].len());
+        total += filters.iter().map(|w| filter_gain(w, &times, doppler)).fold(0.0, f32::max);
+    }
+    total / phases as f32
+}
+
+/// Returns the clutter improvement factor (linear) against a zero mean Gaussian clutter spectrum,
+/// averaged over the filters in the bank
+pub fn improvement_factor(processor: &SignalProcessor, waveform: &Waveform) -> f32 {
+    let filters = processor_weights(processor);
+    let phases = stagger_phases(waveform);
+    let sigma = processor.clutter_spread;
+    let mut total = 0.0;
+    for (weights, phase) in filters.iter().flat_map(|w| (0..phases).map(move |p| (w, p))) {
+        let times = pulse_times(waveform, phase, weights.len());
+        let mut noise = 0.0;
+        let mut clutter = 0.0;
+        for (m, wm) in weights.iter().enumerate() {
+            noise += wm.0.powi(2) + wm.1.powi(2);
+            for (n, wn) in weights.iter().enumerate() {
+                let tau = times[m] - times[n];
+                let rho = (-2.0 * std::f32::consts::PI.powi(2) * sigma.powi(2) * tau.powi(2)).exp();
+                // Real part of w_m * conj(w_n)
+                clutter += (wm.0 * wn.0 + wm.1 * wn.1) * rho;
+            }
+        }
+        total += noise / clutter;
+    }
+    total / (filters.len() * phases) as f32
+}
+
+/// Returns the lowest Doppler, in Hz, above zero at which a target is cancelled along with the clutter.
+/// Only depends on the processor and waveform, so callers can keep it until either changes.
+pub fn first_blind_doppler(processor: &SignalProcessor, waveform: &Waveform) -> Option<f32> {
+    if (processor.mti == MtiFilter::None && processor.doppler_filters <= 1) || !(waveform.prf > 0.0 && waveform.prf.is_finite()) {
+        return None;
+    }
+    let step = waveform.prf / 1000.0;
+    let gain = |doppler: f32| processing_gain(processor, waveform, doppler);
+    let mut passed = false;
+    let mut doppler = step;
+    while doppler < 100.0 * waveform.prf {
+        let current = gain(doppler);
+        if !passed && current > 0.5 {
+            passed = true;
+        } else if passed && current < 0.01 {
+            // Walk down to the bottom of the null
+            while gain(doppler + step) < gain(doppler) {
+                doppler += step;
+            }
+            return Some(doppler);
+        }
+        doppler += step;
+    }
+    None
+}
+
+/// Returns the lowest radial speed, in m/s, above zero at which a target is cancelled along with the clutter
+pub fn first_blind_speed(processor: &SignalProcessor, waveform: &Waveform, wavelength: f32) -> Option<f32> {
+    first_blind_doppler(processor, waveform).map(|doppler| doppler * wavelength / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn waveform(stagger: Vec<f32>) -> Waveform {
+        Waveform{id: 0, prf: 1000.0, pulse_width: 1e-6, pulses: 16, stagger}
+    }
+
+    #[test]
+    fn test_mti() {
+        let two = SignalProcessor{mti: MtiFilter::TwoPulse, doppler_filters: 0, clutter_spread: 10.0};
+        let wf = waveform(Vec::new());
+        // Zero Doppler is cancelled, PRF / 2 passes with twice the noise gain
+        assert!(processing_gain(&two, &wf, 0.0) < 1e-6);
+        assert!((processing_gain(&two, &wf, 500.0) - 2.0).abs() < 1e-3);
+        // Blind at lambda * PRF / 2
+        assert!((first_blind_speed(&two, &wf, 0.1).unwrap() - 50.0).abs() < 0.2);
+
+        // 1 / (1 - rho) for the two pulse canceller
+        let rho = (-2.0 * std::f32::consts::PI.powi(2) * 100.0 * 1e-6_f32).exp();
+        assert!((improvement_factor(&two, &wf) - 1.0 / (1.0 - rho)).abs() / improvement_factor(&two, &wf) < 1e-2);
+        let three = SignalProcessor{mti: MtiFilter::ThreePulse, ..two};
+        assert!(improvement_factor(&three, &wf) > improvement_factor(&two, &wf));
+    }
+
+    #[test]
+    fn test_staggered_prf() {
+        let two = SignalProcessor{mti: MtiFilter::TwoPulse, doppler_filters: 0, clutter_spread: 10.0};
+        let staggered = waveform(vec![25.0, 30.0, 27.0, 31.0]);
+        let first = processing_gain(&two, &staggered, 1000.0);
+        // Stagger fills in the uniform PRF's first blind speed
+        assert!(first > 0.1);
+        assert!(first_blind_speed(&two, &staggered, 0.1).is_none_or(|v| v > 500.0));
+
+        // A stagger that cannot be used is rejected, and processed as a constant PRF if it gets through
+        let zeros = waveform(vec![0.0, 0.0]);
+        assert!(check_waveform(&zeros).is_err());
+        assert!(check_waveform(&staggered).is_ok());
+        assert_eq!(processing_gain(&two, &zeros, 1000.0), processing_gain(&two, &waveform(Vec::new()), 1000.0));
+        assert!((first_blind_speed(&two, &zeros, 0.1).unwrap() - 50.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_filter_bank() {
+        let bank = SignalProcessor{mti: MtiFilter::None, doppler_filters: 8, clutter_spread: 10.0};
+        let wf = waveform(Vec::new());
+        // A return centered on a filter gets the full coherent gain of eight pulses
+        assert!((processing_gain(&bank, &wf, 250.0) - 8.0).abs() < 1e-2);
+        assert!(processing_gain(&bank, &wf, 0.0) < 1e-3);
+        assert!(improvement_factor(&bank, &wf) > 10.0);
+    }
+}
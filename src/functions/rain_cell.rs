@@ -0,0 +1,75 @@
+use super::*;
+
+/// Returns the length, in meters, of the straight path between two positions that lies inside a sphere
+pub fn path_length_in_sphere(pos1: &Position, pos2: &Position, center: &Position, radius: f32) -> f32 {
+    let d = [pos2.x - pos1.x, pos2.y - pos1.y, pos2.z - pos1.z];
+    let length = (d[0].powi(2) + d[1].powi(2) + d[2].powi(2)).sqrt();
+    if length == 0.0 {
+        return 0.0;
+    }
+    let u = [d[0] / length, d[1] / length, d[2] / length];
+    let m = [pos1.x - center.x, pos1.y - center.y, pos1.z - center.z];
+    let b = m[0] * u[0] + m[1] * u[1] + m[2] * u[2];
+    let c = m[0].powi(2) + m[1].powi(2) + m[2].powi(2) - radius.powi(2);
+    let discriminant = b.powi(2) - c;
+    if discriminant <= 0.0 {
+        return 0.0;
+    }
+    let root = discriminant.sqrt();
+    let enter = (-b - root).max(0.0);
+    let exit = (-b + root).min(length);
+    (exit - enter).max(0.0)
+}
+
+/// Returns the fraction of power, 0 - 1, left after the path between two positions crosses any rain cells
+pub fn rain_cell_loss<'a, I>(pos1: &Position, pos2: &Position, frequency: f32, cells: I) -> f32
+where
+    I: Iterator<Item = (&'a RainCell, &'a Position)>,
+{
+    let mut loss_db = 0.0;
+    for (cell, center) in cells {
+        let inside = path_length_in_sphere(pos1, pos2, center, cell.radius);
+        loss_db += rain_attenuation(frequency / 1e9, cell.rain_rate) * inside / 1000.0;
+    }
+    10.0_f32.powf(-loss_db / 10.0)
+}
+
+/// Returns the volume reflectivity, eta (m^2/m^3), of rain using the Marshall-Palmer Z = 200 R^1.6 relation
+pub fn rain_reflectivity(rain_rate: f32, wavelength: f32) -> f32 {
+    // |K|^2 for water
+    let k2 = 0.93;
+    // mm^6/m^3 to m^6/m^3
+    let z = 200.0 * rain_rate.powf(1.6) * 1e-18;
+    std::f32::consts::PI.powi(5) * k2 * z / wavelength.powi(4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_length() {
+        let center = Position{x: 5000.0, y: 0.0, z: 0.0, direction: 0.0};
+        let start = Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0};
+        let through = Position{x: 10000.0, y: 0.0, z: 0.0, direction: 0.0};
+        let inside = Position{x: 5000.0, y: 0.0, z: 0.0, direction: 0.0};
+        let past = Position{x: 0.0, y: 5000.0, z: 0.0, direction: 0.0};
+        assert!((path_length_in_sphere(&start, &through, &center, 1000.0) - 2000.0).abs() < 1e-2);
+        assert!((path_length_in_sphere(&start, &inside, &center, 1000.0) - 1000.0).abs() < 1e-2);
+        assert_eq!(path_length_in_sphere(&start, &past, &center, 1000.0), 0.0);
+
+        // 2 km through 10 mm/h at X-band is ~0.44 dB
+        let cell = RainCell{radius: 1000.0, rain_rate: 10.0, wind_x: 0.0, wind_y: 0.0, velocity_spread: 0.0};
+        let loss = rain_cell_loss(&start, &through, 10e9, vec![(&cell, &center)].into_iter());
+        assert!((10.0 * loss.log10() + 0.439).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_rain_reflectivity() {
+        // 4 mm/h is about 33 dBZ; at S-band that is roughly 5e-9 m^-1
+        let eta = rain_reflectivity(4.0, 0.1);
+        assert!(eta > 1e-9 && eta < 1e-8);
+        // Rayleigh scattering grows as 1 / lambda^4
+        assert!((rain_reflectivity(4.0, 0.05) / eta - 16.0).abs() < 1e-2);
+    }
+}
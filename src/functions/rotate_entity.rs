@@ -1,10 +1,24 @@
 use super::*;
 
-pub fn rotate_entity(pos: &mut Position, sen: &Antenna) {
-    pos.direction = (pos.direction + sen.azimuth_beam_width / 2.0) % 360.0;
+pub fn rotate_entity(pos: &mut Position, step: f32) {
+    pos.direction = (pos.direction + step) % 360.0;
 
 }
 
+/// Scans a boresight by `step` degrees, leaving the platform's heading alone
+pub fn rotate_boresight(bore: &mut Boresight, step: f32) {
+    bore.azimuth = (bore.azimuth + step) % 360.0;
+}
+
+/// Degrees an antenna's beam moves in one time step
+pub fn scan_step(scan: Option<&Scan>, sen: &Antenna, delta: f32) -> f32 {
+    match scan {
+        None | Some(Scan::Step) => sen.azimuth_beam_width / 2.0,
+        Some(Scan::Circular{rate}) => rate * delta,
+        Some(Scan::Fixed) => 0.0,
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
@@ -12,4 +26,4 @@ pub fn rotate_entity(pos: &mut Position, sen: &Antenna) {
 //     #[test]
 //     fn test_rotate() {
 //     }
-// }
\ No newline at end of file
+// }
@@ -0,0 +1,276 @@
+use super::*;
+use specs::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+pub fn parse_scenario_json(text: &str) -> Result<Scenario, String> {
+    serde_json::from_str(text).map_err(|e| e.to_string())
+}
+
+pub fn parse_scenario_toml(text: &str) -> Result<Scenario, String> {
+    toml::from_str(text).map_err(|e| e.to_string())
+}
+
+/// Loads a scenario file, choosing the format by extension (.toml for TOML, anything else as JSON).
+/// Paths inside the scenario are resolved relative to the file.
+pub fn load_scenario<P: AsRef<Path>>(path: P) -> Result<Scenario, String> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut scenario = if path.extension().is_some_and(|ext| ext == "toml") {
+        parse_scenario_toml(&text)
+    } else {
+        parse_scenario_json(&text)
+    }.map_err(|e| format!("{}: {}", path.display(), e))?;
+    scenario.base_dir = path.parent().map(|dir| dir.to_path_buf()).unwrap_or_default();
+    Ok(scenario)
+}
+
+pub fn antenna_from_spec(spec: &AntennaSpec) -> Antenna {
+    Antenna{
+        frequency: spec.frequency,
+        gain: 10.0_f32.powf(spec.gain_db / 10.0),
+        power: spec.power,
+        wavelength: C / spec.frequency,
+        azimuth_beam_width: spec.azimuth_beam_width,
+        elevation_beam_width: spec.elevation_beam_width
+    }
+}
+
+pub fn load_rcs(spec: RcsSpec, base_dir: &Path) -> Result<RCS, String> {
+    match spec {
+        RcsSpec::File(file) => {
+            let path = base_dir.join(file);
+            let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+        },
+        RcsSpec::Constant(rcs) => Ok(isotropic_rcs(rcs)),
+        RcsSpec::Table(rcs) => Ok(rcs),
+    }
+}
+
+fn check_antenna(name: &str, spec: &AntennaSpec) -> Result<(), String> {
+    if spec.frequency <= 0.0 || spec.azimuth_beam_width <= 0.0 || spec.elevation_beam_width <= 0.0 {
+        return Err(format!("{}: antenna frequency and beam widths must be positive", name));
+    }
+    Ok(())
+}
+
+/// Creates the scenario's entities and resources in the world, registering every component it uses.
+/// Returns the entity created for each name.
+pub fn populate_world(world: &mut World, scenario: Scenario) -> Result<HashMap<String, Entity>, String> {
+    if scenario.time_step <= 0.0 || scenario.duration < 0.0 {
+        return Err("time_step must be positive and duration must not be negative".to_string());
+    }
+    world.register::<Position>();
+    world.register::<Velocity>();
+    world.register::<Trajectory>();
+    world.register::<Antenna>();
+    world.register::<Waveform>();
+    world.register::<Scan>();
+    world.register::<SignalProcessor>();
+    world.register::<Receiver>();
+    world.register::<RCS>();
+    world.register::<TargetIllumination>();
+    world.register::<Jammer>();
+    world.register::<StandOffJammer>();
+    world.register::<RainCell>();
+
+    let base_dir = scenario.base_dir;
+    let environment = scenario.environment;
+    check_clutter(&environment.clutter)?;
+    if let Some(terrain) = environment.terrain {
+        world.insert(load_terrain(base_dir.join(terrain))?);
+    }
+    if let Some(library) = scenario.emitter_library {
+        world.insert(load_emitter_library(base_dir.join(library))?);
+    }
+    world.insert(environment.weather);
+    world.insert(environment.clutter);
+    world.insert(environment.surface);
+    world.insert(environment.earth);
+    world.insert(SimulationTime{delta: scenario.time_step, elapsed: 0.0});
+    world.insert(SimulationRng::new(scenario.seed));
+    for spec in environment.rain_cells {
+        world.create_entity().with(spec.position).with(spec.cell).build();
+    }
+
+    let mut names: HashMap<String, Entity> = HashMap::new();
+    let mut name_entity = |name: String, entity: Entity| match names.insert(name.clone(), entity) {
+        Some(_) => Err(format!("{}: name is used more than once", name)),
+        None => Ok(()),
+    };
+
+    for radar in scenario.radars {
+        check_antenna(&radar.name, &radar.antenna)?;
+        if let Some(waveform) = radar.waveform.as_ref() {
+            check_waveform(waveform).map_err(|e| format!("{}: {}", radar.name, e))?;
+        }
+        let mut builder = world.create_entity()
+        .with(radar.position)
+        .with(antenna_from_spec(&radar.antenna))
+        .with(Receiver::default());
+        if let Some(waveform) = radar.waveform {
+            builder = builder.with(waveform);
+        }
+        if let Some(scan) = radar.scan {
+            builder = builder.with(scan);
+        }
+        if let Some(processor) = radar.signal_processor {
+            builder = builder.with(processor);
+        }
+        name_entity(radar.name, builder.build())?;
+    }
+
+    for TargetSpec{name, position, velocity, rcs, trajectory} in scenario.targets {
+        let rcs = load_rcs(rcs, &base_dir).map_err(|e| format!("{}: {}", name, e))?;
+        let mut builder = world.create_entity()
+        .with(position)
+        .with(rcs)
+        .with(TargetIllumination{illuminations: Vec::new()});
+        if let Some(velocity) = velocity {
+            builder = builder.with(velocity);
+        }
+        if let Some(trajectory) = trajectory {
+            builder = builder.with(trajectory);
+        }
+        name_entity(name, builder.build())?;
+    }
+
+    // Stand-off jammers refer to radars and targets by name, so they come last
+    let mut stand_off: Vec<(Entity, String, StandOffSpec)> = Vec::new();
+    for JammerSpec{name, position, velocity, antenna, jammer, rcs, trajectory, stand_off: spec} in scenario.jammers {
+        check_antenna(&name, &antenna)?;
+        let mut builder = world.create_entity()
+        .with(position)
+        .with(antenna_from_spec(&antenna))
+        .with(jammer);
+        if let Some(rcs) = rcs {
+            let rcs = load_rcs(rcs, &base_dir).map_err(|e| format!("{}: {}", name, e))?;
+            builder = builder.with(rcs).with(TargetIllumination{illuminations: Vec::new()});
+        }
+        if let Some(velocity) = velocity {
+            builder = builder.with(velocity);
+        }
+        if let Some(trajectory) = trajectory {
+            builder = builder.with(trajectory);
+        }
+        let entity = builder.build();
+        if let Some(spec) = spec {
+            stand_off.push((entity, name.clone(), spec));
+        }
+        name_entity(name, entity)?;
+    }
+    let lookup = |owner: &str, name: &str| names.get(name).copied().ok_or_else(|| format!("{}: no radar or target named {}", owner, name));
+    for (entity, name, spec) in stand_off {
+        let mut victims = Vec::new();
+        for victim in spec.victims {
+            victims.push(match victim {
+                VictimSpec::Radar{radar, priority} => Victim{target: JammerTarget::Radar(lookup(&name, &radar)?), priority},
+                VictimSpec::Sector{azimuth, width, priority} => Victim{target: JammerTarget::Sector{azimuth, width}, priority},
+            });
+        }
+        let protected = spec.protected.iter().map(|target| lookup(&name, target)).collect::<Result<Vec<Entity>, String>>()?;
+        if let Err(e) = world.write_storage::<StandOffJammer>().insert(entity, StandOffJammer{
+            victims,
+            protected,
+            escort_priority: spec.escort_priority,
+            max_beams: spec.max_beams
+        }) {
+            println!("{:?}", e);
+        }
+    }
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCENARIO: &str = r#"
+        name = "Escort"
+        duration = 10.0
+        time_step = 0.5
+        seed = 7
+
+        [environment.weather]
+        rain_rate = 4.0
+
+        [environment.clutter]
+        model = { ConstantGamma = { terrain = "Farmland" } }
+
+        [[radars]]
+        name = "search"
+        position = { x = 0.0, y = 0.0, z = 10.0 }
+        antenna = { frequency = 3.0e9, gain_db = 30.0, power = 1.0e5, azimuth_beam_width = 2.0, elevation_beam_width = 10.0 }
+        waveform = { prf = 1000.0, pulse_width = 1.0e-6, pulses = 16 }
+        scan = { Circular = { rate = 36.0 } }
+        signal_processor = { mti = "TwoPulse", doppler_filters = 0, clutter_spread = 10.0 }
+
+        [[targets]]
+        name = "striker"
+        position = { x = 20000.0, y = 0.0, z = 3000.0 }
+        velocity = { x = -250.0, y = 0.0, z = 0.0 }
+        rcs = { Constant = 5.0 }
+
+        [[jammers]]
+        name = "escort"
+        position = { x = 60000.0, y = 0.0, z = 8000.0 }
+        antenna = { frequency = 3.0e9, gain_db = 10.0, power = 1000.0, azimuth_beam_width = 30.0, elevation_beam_width = 30.0 }
+        jammer = { mode = "Spot", center_frequency = 0.0, bandwidth = 1.0e7 }
+        stand_off = { victims = [{ Radar = { radar = "search", priority = 1.0 } }], protected = ["striker"], escort_priority = 2.0, max_beams = 2 }
+    "#;
+
+    #[test]
+    fn test_scenario_toml() {
+        let scenario = parse_scenario_toml(SCENARIO).unwrap();
+        assert_eq!(scenario.duration, 10.0);
+        assert_eq!(scenario.radars[0].scan, Some(Scan::Circular{rate: 36.0}));
+
+        let mut world = World::new();
+        let names = populate_world(&mut world, scenario).unwrap();
+        assert_eq!(names.len(), 3);
+        assert_eq!(world.read_resource::<SimulationTime>().delta, 0.5);
+        assert_eq!(world.read_resource::<Weather>().rain_rate, 4.0);
+        // Fields missing from the file keep their defaults
+        assert_eq!(world.read_resource::<Weather>().temperature, 15.0);
+        assert_eq!(world.read_resource::<Clutter>().range_resolution, 150.0);
+
+        let antennas = world.read_storage::<Antenna>();
+        let radar = antennas.get(names["search"]).unwrap();
+        assert!((radar.gain - 1000.0).abs() < 1e-2);
+        assert!((radar.wavelength - 0.1).abs() < 1e-3);
+        assert_eq!(world.read_storage::<RCS>().get(names["striker"]).unwrap().avg_rcs, 5.0);
+        let stand_off = world.read_storage::<StandOffJammer>();
+        let escort = stand_off.get(names["escort"]).unwrap();
+        assert_eq!(escort.victims[0].target, JammerTarget::Radar(names["search"]));
+        assert_eq!(escort.protected, vec![names["striker"]]);
+    }
+
+    #[test]
+    fn test_scenario_json() {
+        let scenario = load_scenario("scenarios/single_radar.json").unwrap();
+        let mut world = World::new();
+        let names = populate_world(&mut world, scenario).unwrap();
+        // The RCS file is found relative to the scenario
+        assert!(world.read_storage::<RCS>().get(names["target"]).is_some());
+        assert!(world.read_storage::<Trajectory>().get(names["target"]).is_some());
+        // The TOML copy describes the same scenario
+        let toml = load_scenario("scenarios/single_radar.toml").unwrap();
+        assert_eq!(toml.radars[0].antenna, AntennaSpec{
+            frequency: 9.4e9,
+            gain_db: 32.0,
+            power: 100000.0,
+            azimuth_beam_width: 10.0,
+            elevation_beam_width: 20.0
+        });
+        assert_eq!(toml.targets[0].trajectory.as_ref().map(|t| t.waypoints.len()), Some(2));
+
+        // Unknown names and missing files are reported rather than panicking
+        let broken = SCENARIO.replace("radar = \"search\"", "radar = \"tracker\"");
+        assert!(populate_world(&mut World::new(), parse_scenario_toml(&broken).unwrap()).is_err());
+        let bad_clutter = SCENARIO.replace("[environment.clutter]", "[environment.clutter]\nstatistics = { Weibull = { shape = 0.0 } }");
+        assert!(populate_world(&mut World::new(), parse_scenario_toml(&bad_clutter).unwrap()).is_err());
+        assert!(load_scenario("scenarios/missing.json").is_err());
+    }
+}
@@ -0,0 +1,40 @@
+use super::*;
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+/// Advances a Singer maneuver acceleration by `dt` seconds.
+/// Each axis follows a first-order Gauss-Markov process with correlation time `tau`
+/// and steady state standard deviation `sigma`.
+pub fn singer_acceleration<R: Rng>(acc: &mut Acceleration, tau: f32, sigma: f32, dt: f32, rng: &mut R) {
+    let rho = (-dt / tau).exp();
+    let drive = sigma * (1.0 - rho.powi(2)).sqrt();
+    acc.x = rho * acc.x + drive * rng.sample::<f32, _>(StandardNormal);
+    acc.y = rho * acc.y + drive * rng.sample::<f32, _>(StandardNormal);
+    acc.z = rho * acc.z + drive * rng.sample::<f32, _>(StandardNormal);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singer() {
+        let mut rng = SimulationRng::new(7).rng;
+        let mut acc = Acceleration{x: 0.0, y: 0.0, z: 0.0};
+        let (mut sum, mut count) = (0.0, 0.0);
+        for _ in 0..20000 {
+            singer_acceleration(&mut acc, 10.0, 5.0, 1.0, &mut rng);
+            sum += acc.x.powi(2);
+            count += 1.0;
+        }
+        // Steady state rms should approach sigma
+        assert!(((sum / count as f32).sqrt() - 5.0).abs() < 0.5);
+
+        // The same seed gives the same maneuver
+        let mut a = Acceleration{x: 1.0, y: 0.0, z: 0.0};
+        let mut b = Acceleration{x: 1.0, y: 0.0, z: 0.0};
+        singer_acceleration(&mut a, 10.0, 5.0, 1.0, &mut SimulationRng::new(3).rng);
+        singer_acceleration(&mut b, 10.0, 5.0, 1.0, &mut SimulationRng::new(3).rng);
+        assert_eq!(a, b);
+    }
+}
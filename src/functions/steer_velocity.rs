@@ -0,0 +1,106 @@
+use super::*;
+
+/// Standard gravity, m/s^2
+pub const G: f32 = 9.80665;
+
+/// Returns the turn rate limit, in degrees / s, for a trajectory flown at the given speed.
+/// A g-limit is converted with omega = n * g / v and the tighter of the two limits is used.
+pub fn max_turn_rate(traj: &Trajectory, speed: f32) -> f32 {
+    let mut limit = traj.max_turn_rate.unwrap_or(f32::INFINITY);
+    if let Some(max_g) = traj.max_g {
+        if speed > 0.0 {
+            limit = limit.min((max_g * G / speed).to_degrees());
+        }
+    }
+    limit
+}
+
+/// Turns a velocity towards a waypoint by at most `max_turn` degrees, flying at the waypoint's leg speed
+pub fn steer_velocity(pos: &Position, vel: &Velocity, waypoint: &Waypoint, max_turn: f32) -> Velocity {
+    let (dx, dy, dz) = (waypoint.x - pos.x, waypoint.y - pos.y, waypoint.z - pos.z);
+    let dist = (dx.powi(2) + dy.powi(2) + dz.powi(2)).sqrt();
+    let speed = (vel.x.powi(2) + vel.y.powi(2) + vel.z.powi(2)).sqrt();
+    if dist == 0.0 {
+        return Velocity{x: vel.x, y: vel.y, z: vel.z};
+    }
+    let desired = [dx / dist, dy / dist, dz / dist];
+    if speed == 0.0 {
+        return scale(desired, waypoint.speed);
+    }
+    let current = [vel.x / speed, vel.y / speed, vel.z / speed];
+
+    let cos_theta = (current[0] * desired[0] + current[1] * desired[1] + current[2] * desired[2]).clamp(-1.0, 1.0);
+    let theta = cos_theta.acos();
+    let phi = max_turn.to_radians();
+    if theta <= phi {
+        return scale(desired, waypoint.speed);
+    }
+
+    let sin_theta = theta.sin();
+    let heading = if sin_theta.abs() < 1e-6 {
+        // Waypoint is directly behind us, so any turn plane works. Prefer a level turn
+        let horizontal = (current[0].powi(2) + current[1].powi(2)).sqrt();
+        let perp = if horizontal > 1e-6 {
+            [-current[1] / horizontal, current[0] / horizontal, 0.0]
+        } else {
+            [1.0, 0.0, 0.0]
+        };
+        [
+            phi.cos() * current[0] + phi.sin() * perp[0],
+            phi.cos() * current[1] + phi.sin() * perp[1],
+            phi.cos() * current[2] + phi.sin() * perp[2],
+        ]
+    } else {
+        // Spherical interpolation between the current and desired headings
+        let a = (theta - phi).sin() / sin_theta;
+        let b = phi.sin() / sin_theta;
+        [
+            a * current[0] + b * desired[0],
+            a * current[1] + b * desired[1],
+            a * current[2] + b * desired[2],
+        ]
+    };
+    scale(heading, waypoint.speed)
+}
+
+fn scale(dir: [f32; 3], speed: f32) -> Velocity {
+    Velocity{x: dir[0] * speed, y: dir[1] * speed, z: dir[2] * speed}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steer() {
+        let pos = Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0};
+        let vel = Velocity{x: 100.0, y: 0.0, z: 0.0};
+        let waypoint = Waypoint{x: 0.0, y: 1000.0, z: 0.0, speed: 200.0};
+
+        // Unlimited turn goes straight to the waypoint
+        let new_vel = steer_velocity(&pos, &vel, &waypoint, 180.0);
+        assert!(new_vel.x.abs() < 1e-3);
+        assert!((new_vel.y - 200.0).abs() < 1e-3);
+
+        // Limited turn only rotates 30 degrees
+        let new_vel = steer_velocity(&pos, &vel, &waypoint, 30.0);
+        assert!((new_vel.y.atan2(new_vel.x).to_degrees() - 30.0).abs() < 1e-3);
+        assert!(((new_vel.x.powi(2) + new_vel.y.powi(2)).sqrt() - 200.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_turn_limit() {
+        let traj = Trajectory{
+            waypoints: Vec::new(),
+            current: 0,
+            max_turn_rate: Some(10.0),
+            max_g: Some(2.0),
+            capture_radius: 100.0,
+            cyclic: false
+        };
+        // 2g at 100 m/s is ~11.2 deg/s, so the turn rate limit wins
+        assert_eq!(max_turn_rate(&traj, 100.0), 10.0);
+        // 2g at 300 m/s is ~3.7 deg/s
+        assert!((max_turn_rate(&traj, 300.0) - 3.7456).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,184 @@
+use super::*;
+use std::fs;
+use std::path::Path;
+
+/// Parses an ESRI ASCII grid. Rows are listed north to south; NODATA cells become sea level.
+pub fn parse_ascii_grid(text: &str) -> Result<Terrain, String> {
+    let mut tokens = text.split_whitespace();
+    let mut header = |name: &str| -> Result<String, String> {
+        match (tokens.next(), tokens.next()) {
+            (Some(key), Some(value)) if key.eq_ignore_ascii_case(name) => Ok(value.to_string()),
+            _ => Err(format!("missing '{}' in grid header", name)),
+        }
+    };
+    let parse_err = |e: std::num::ParseFloatError| e.to_string();
+    let ncols: usize = header("ncols")?.parse().map_err(|e: std::num::ParseIntError| e.to_string())?;
+    let nrows: usize = header("nrows")?.parse().map_err(|e: std::num::ParseIntError| e.to_string())?;
+    let xll: f32 = header("xllcorner")?.parse().map_err(parse_err)?;
+    let yll: f32 = header("yllcorner")?.parse().map_err(parse_err)?;
+    let cell_size: f32 = header("cellsize")?.parse().map_err(parse_err)?;
+    let nodata: f32 = header("NODATA_value")?.parse().map_err(parse_err)?;
+
+    check_cell_size(cell_size)?;
+    let cells = ncols.checked_mul(nrows).filter(|&cells| cells > 0).ok_or("grid must have at least one row and column")?;
+
+    let values = tokens.map(|t| t.parse::<f32>().map_err(parse_err)).collect::<Result<Vec<f32>, String>>()?;
+    if values.len() != cells {
+        return Err(format!("expected {} heights, found {}", cells, values.len()));
+    }
+    let mut heights = Vec::with_capacity(values.len());
+    for row in values.chunks(ncols).rev() {
+        heights.extend(row.iter().map(|&h| if h == nodata { 0.0 } else { h }));
+    }
+    Ok(Terrain{
+        ncols,
+        nrows,
+        x_origin: xll + cell_size / 2.0,
+        y_origin: yll + cell_size / 2.0,
+        cell_size,
+        heights
+    })
+}
+
+/// Parses a binary heightmap: little endian u32 ncols, u32 nrows, f32 x_origin, f32 y_origin,
+/// f32 cell_size, followed by ncols * nrows f32 heights starting from the southern row.
+pub fn parse_heightmap(bytes: &[u8]) -> Result<Terrain, String> {
+    let word = |i: usize| -> Result<[u8; 4], String> {
+        let mut w = [0; 4];
+        w.copy_from_slice(bytes.get(i * 4..i * 4 + 4).ok_or("heightmap is truncated")?);
+        Ok(w)
+    };
+    let ncols = u32::from_le_bytes(word(0)?) as usize;
+    let nrows = u32::from_le_bytes(word(1)?) as usize;
+    let cell_size = f32::from_le_bytes(word(4)?);
+    check_cell_size(cell_size)?;
+    // The header is checked against the file length before anything is allocated from it
+    let expected = ncols.checked_mul(nrows).and_then(|cells| cells.checked_mul(4)).and_then(|len| len.checked_add(20));
+    if expected != Some(bytes.len()) {
+        return Err(format!("heightmap header gives {} x {} heights, which does not match the file length of {} bytes", ncols, nrows, bytes.len()));
+    }
+    let heights = bytes[20..].chunks_exact(4).map(|w| f32::from_le_bytes([w[0], w[1], w[2], w[3]])).collect();
+    Ok(Terrain{
+        ncols,
+        nrows,
+        x_origin: f32::from_le_bytes(word(2)?),
+        y_origin: f32::from_le_bytes(word(3)?),
+        cell_size,
+        heights
+    })
+}
+
+fn check_cell_size(cell_size: f32) -> Result<(), String> {
+    if cell_size > 0.0 && cell_size.is_finite() {
+        Ok(())
+    } else {
+        Err(format!("cell size must be positive, found {}", cell_size))
+    }
+}
+
+/// Loads a DEM file, choosing the format by extension (.asc for ASCII grids, anything else as a binary heightmap)
+pub fn load_terrain<P: AsRef<Path>>(path: P) -> Result<Terrain, String> {
+    let path = path.as_ref();
+    if path.extension().is_some_and(|ext| ext == "asc") {
+        parse_ascii_grid(&fs::read_to_string(path).map_err(|e| e.to_string())?)
+    } else {
+        parse_heightmap(&fs::read(path).map_err(|e| e.to_string())?)
+    }
+}
+
+/// Returns the bilinearly interpolated terrain height at a point, or None off the grid
+pub fn terrain_height(terrain: &Terrain, x: f32, y: f32) -> Option<f32> {
+    if terrain.ncols == 0 || terrain.nrows == 0 {
+        return None;
+    }
+    let col = (x - terrain.x_origin) / terrain.cell_size;
+    let row = (y - terrain.y_origin) / terrain.cell_size;
+    if col < 0.0 || row < 0.0 || col > (terrain.ncols - 1) as f32 || row > (terrain.nrows - 1) as f32 {
+        return None;
+    }
+    let (c0, r0) = (col.floor() as usize, row.floor() as usize);
+    let (c1, r1) = ((c0 + 1).min(terrain.ncols - 1), (r0 + 1).min(terrain.nrows - 1));
+    let (fc, fr) = (col - c0 as f32, row - r0 as f32);
+    let h = |c: usize, r: usize| terrain.heights[r * terrain.ncols + c];
+    let south = h(c0, r0) * (1.0 - fc) + h(c1, r0) * fc;
+    let north = h(c0, r1) * (1.0 - fc) + h(c1, r1) * fc;
+    Some(south * (1.0 - fr) + north * fr)
+}
+
+/// Returns true if the straight path between two positions stays above the terrain.
+/// The path is sampled every half cell, skipping the end points where the entities sit.
+pub fn terrain_clear(terrain: &Terrain, pos1: &Position, pos2: &Position) -> bool {
+    if terrain.heights.is_empty() || terrain.cell_size <= 0.0 || terrain.cell_size.is_nan() {
+        return true;
+    }
+    let ground_range = ((pos2.x - pos1.x).powi(2) + (pos2.y - pos1.y).powi(2)).sqrt();
+    let steps = (ground_range / (terrain.cell_size / 2.0)).ceil() as usize;
+    for i in 1..steps {
+        let t = i as f32 / steps as f32;
+        let x = pos1.x + (pos2.x - pos1.x) * t;
+        let y = pos1.y + (pos2.y - pos1.y) * t;
+        let z = pos1.z + (pos2.z - pos1.z) * t;
+        if let Some(height) = terrain_height(terrain, x, y) {
+            if height > z {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ridge() -> Terrain {
+        // 3 x 3 grid with a 500 m ridge running north-south through the middle column
+        parse_ascii_grid("ncols 3\nnrows 3\nxllcorner -500\nyllcorner -500\ncellsize 1000\nNODATA_value -9999\n\
+            0 500 0\n0 500 0\n-9999 500 0\n").unwrap()
+    }
+
+    #[test]
+    fn test_ascii_grid() {
+        let terrain = ridge();
+        assert_eq!(terrain.x_origin, 0.0);
+        assert_eq!(terrain.heights, vec![0.0, 500.0, 0.0, 0.0, 500.0, 0.0, 0.0, 500.0, 0.0]);
+        assert_eq!(terrain_height(&terrain, 500.0, 1000.0), Some(250.0));
+        assert_eq!(terrain_height(&terrain, 5000.0, 0.0), None);
+        assert!(parse_ascii_grid("ncols 2\nnrows 1\nxllcorner 0\nyllcorner 0\ncellsize 1\nNODATA_value 0\n1\n").is_err());
+        assert!(parse_ascii_grid("ncols 1\nnrows 1\nxllcorner 0\nyllcorner 0\ncellsize 0\nNODATA_value 0\n1\n").is_err());
+        assert!(parse_ascii_grid("ncols 0\nnrows 1\nxllcorner 0\nyllcorner 0\ncellsize 1\nNODATA_value 0\n").is_err());
+    }
+
+    #[test]
+    fn test_heightmap() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        for v in [0.0f32, 0.0, 10.0, 1.0, 2.0].iter() {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        let terrain = parse_heightmap(&bytes).unwrap();
+        assert_eq!(terrain.heights, vec![1.0, 2.0]);
+        assert_eq!(terrain.cell_size, 10.0);
+        assert!(parse_heightmap(&bytes[..bytes.len() - 1]).is_err());
+
+        // A corrupt header is rejected rather than allocated from
+        let mut huge = bytes.clone();
+        huge[..8].copy_from_slice(&[0xff; 8]);
+        assert!(parse_heightmap(&huge).is_err());
+        let mut bad_cell_size = bytes.clone();
+        bad_cell_size[16..20].copy_from_slice(&f32::NAN.to_le_bytes());
+        assert!(parse_heightmap(&bad_cell_size).is_err());
+    }
+
+    #[test]
+    fn test_masking() {
+        let terrain = ridge();
+        let west = Position{x: 0.0, y: 1000.0, z: 100.0, direction: 0.0};
+        let east = Position{x: 2000.0, y: 1000.0, z: 100.0, direction: 0.0};
+        let east_high = Position{x: 2000.0, y: 1000.0, z: 2000.0, direction: 0.0};
+        assert!(!terrain_clear(&terrain, &west, &east));
+        assert!(terrain_clear(&terrain, &west, &east_high));
+        assert!(terrain_clear(&Terrain::default(), &west, &east));
+    }
+}
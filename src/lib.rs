@@ -1,4 +1,5 @@
 pub mod systems;
 pub mod structs;
 pub mod components;
-pub mod functions;
\ No newline at end of file
+pub mod functions;
+pub mod simulation;
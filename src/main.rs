@@ -0,0 +1,195 @@
+use specs::prelude::*;
+use radar_ecs::simulation::Simulation;
+use radar_ecs::structs::*;
+use radar_ecs::functions::{load_scenario, populate_world};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write as IoWrite};
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "Usage: radar_ecs <scenario.json|scenario.toml> [options]
+
+Runs a scenario headless and writes the results to the output directory.
+
+Options:
+    --duration <s>      simulated seconds to run, overrides the scenario
+    --ticks <n>         number of time steps to run, overrides --duration
+    --dt <s>            time step in seconds, overrides the scenario
+    --seed <n>          random seed, overrides the scenario
+    --output <dir>      directory for detections.csv and positions.csv (default: output)
+    -v, --verbose       print a line every tick
+    -q, --quiet         only print errors
+    -h, --help          print this message";
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    scenario: PathBuf,
+    duration: Option<f32>,
+    ticks: Option<u32>,
+    time_step: Option<f32>,
+    seed: Option<u64>,
+    output: PathBuf,
+    verbosity: u8,      // 0 quiet, 1 summary, 2 every tick
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Options>, String> {
+    let mut options = Options{
+        scenario: PathBuf::new(),
+        duration: None,
+        ticks: None,
+        time_step: None,
+        seed: None,
+        output: PathBuf::from("output"),
+        verbosity: 1
+    };
+    let mut scenario: Option<PathBuf> = None;
+    fn value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+        let value = value.ok_or_else(|| format!("{} needs a value", flag))?;
+        value.parse().map_err(|_| format!("invalid value for {}: {}", flag, value))
+    }
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--duration" => options.duration = Some(value(&arg, args.next())?),
+            "--ticks" => options.ticks = Some(value(&arg, args.next())?),
+            "--dt" => options.time_step = Some(value(&arg, args.next())?),
+            "--seed" => options.seed = Some(value(&arg, args.next())?),
+            "--output" => options.output = value(&arg, args.next())?,
+            "-v" | "--verbose" => options.verbosity = 2,
+            "-q" | "--quiet" => options.verbosity = 0,
+            flag if flag.starts_with('-') => return Err(format!("unknown option {}", flag)),
+            path if scenario.is_none() => scenario = Some(PathBuf::from(path)),
+            extra => return Err(format!("unexpected argument {}", extra)),
+        }
+    }
+    options.scenario = scenario.ok_or_else(|| "no scenario file given".to_string())?;
+    if options.duration.is_some_and(|d| d < 0.0) || options.time_step.is_some_and(|dt| dt <= 0.0) {
+        return Err("duration must not be negative and dt must be positive".to_string());
+    }
+    Ok(Some(options))
+}
+
+fn run(options: Options) -> Result<(), String> {
+    let mut scenario = load_scenario(&options.scenario)?;
+    if let Some(time_step) = options.time_step {
+        scenario.time_step = time_step;
+    }
+    if let Some(seed) = options.seed {
+        scenario.seed = seed;
+    }
+    let duration = options.duration.unwrap_or(scenario.duration);
+    let time_step = scenario.time_step;
+    let ticks = options.ticks.unwrap_or((duration / time_step).round() as u32);
+    let title = scenario.name.clone();
+
+    let mut simulation = Simulation::new();
+    let names: HashMap<Entity, String> = populate_world(&mut simulation.world, scenario)?
+        .into_iter().map(|(name, entity)| (entity, name)).collect();
+
+    fs::create_dir_all(&options.output).map_err(|e| format!("{}: {}", options.output.display(), e))?;
+    let create = |file: &str| {
+        let path = options.output.join(file);
+        File::create(&path).map(BufWriter::new).map_err(|e| format!("{}: {}", path.display(), e))
+    };
+    let mut detections = create("detections.csv")?;
+    let mut positions = create("positions.csv")?;
+    let io_error = |e: std::io::Error| e.to_string();
+    writeln!(detections, "time,radar,range,angle,power,doppler,processing_gain,jamming_to_signal,interference_targets").map_err(io_error)?;
+    writeln!(positions, "time,name,x,y,z").map_err(io_error)?;
+
+    if options.verbosity > 0 {
+        println!("Running {} for {} ticks of {} s", if title.is_empty() { "scenario" } else { &title }, ticks, time_step);
+    }
+    let mut total_signals = 0;
+    for tick in 0..ticks {
+        simulation.step();
+        let world = &simulation.world;
+
+        // Results are stamped with the time at the start of the tick
+        let time = tick as f32 * time_step;
+        let entities = world.entities();
+        let mut signals = 0;
+        for (entity, receiver) in (&entities, &world.read_storage::<Receiver>()).join() {
+            let radar = names.get(&entity).map_or("", |name| name.as_str());
+            for signal in receiver.signals.iter() {
+                writeln!(detections, "{},{},{},{},{},{},{},{},{}", time, radar, signal.range, signal.angle, signal.power,
+                    signal.doppler, signal.processing_gain, signal.jamming_to_signal, receiver.interference_targets.len()).map_err(io_error)?;
+            }
+            signals += receiver.signals.len();
+        }
+        for (entity, pos) in (&entities, &world.read_storage::<Position>()).join() {
+            if let Some(name) = names.get(&entity) {
+                writeln!(positions, "{},{},{},{},{}", time, name, pos.x, pos.y, pos.z).map_err(io_error)?;
+            }
+        }
+        total_signals += signals;
+        if options.verbosity > 1 {
+            println!("t = {} s: {} signals received", time, signals);
+        }
+    }
+    detections.flush().map_err(io_error)?;
+    positions.flush().map_err(io_error)?;
+    if options.verbosity > 0 {
+        println!("Done, {} signals received, results in {}", total_signals, options.output.display());
+    }
+    Ok(())
+}
+
+fn main() {
+    match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => {
+            if let Err(e) = run(options) {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        },
+        Ok(None) => println!("{}", USAGE),
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+        line.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse_args(args("scenarios/single_radar.toml --ticks 5 --dt 0.1 --seed 3 --output out -q")).unwrap().unwrap();
+        assert_eq!(options, Options{
+            scenario: PathBuf::from("scenarios/single_radar.toml"),
+            duration: None,
+            ticks: Some(5),
+            time_step: Some(0.1),
+            seed: Some(3),
+            output: PathBuf::from("out"),
+            verbosity: 0
+        });
+        assert_eq!(parse_args(args("--help")).unwrap(), None);
+        assert!(parse_args(args("--ticks 5")).is_err());
+        assert!(parse_args(args("a.json --ticks five")).is_err());
+        assert!(parse_args(args("a.json --dt 0")).is_err());
+        assert!(parse_args(args("a.json --frobnicate")).is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let output = std::env::temp_dir().join("radar_ecs_test_run");
+        let options = parse_args(args("scenarios/single_radar.json --ticks 3 -q")).unwrap().unwrap();
+        run(Options{output: output.clone(), ..options}).unwrap();
+        let positions = fs::read_to_string(output.join("positions.csv")).unwrap();
+        // Header plus the radar and target every tick
+        assert_eq!(positions.lines().count(), 1 + 2 * 3);
+        assert!(fs::read_to_string(output.join("detections.csv")).unwrap().starts_with("time,radar"));
+
+        let missing = parse_args(args("scenarios/missing.json -q")).unwrap().unwrap();
+        assert!(run(missing).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use specs::prelude::*;
+use crate::components::register_components;
+use crate::structs::*;
+use crate::systems::*;
+
+/// The four stages of a tick, run in this order with `World::maintain` between them so
+/// entities created by one stage are seen by the next. A last, fixed stage then removes the
+/// tick's waves and advances the clock (wave_cleanup, clock); user systems cannot be added to it.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    // Entities given in latitude / longitude are placed in the local frame, then
+    // radars and their ECCM decide what to send and create the outgoing waves.
+    // Built-in systems: geodetic, eccm, transmit_signal
+    Transmission,
+    // Waves reach targets and clutter.
+    // Built-in systems: radar_sensing, clutter, weather_clutter
+    Illumination,
+    // Targets, jammers and expendables answer their illuminations.
+    // Built-in systems: doppler_shift, rcs_system, jamming_system, stand_off_jamming, jamming_analysis,
+    // chaff, dispenser, deception_jamming, reflection_creation
+    Reflection,
+    // Receivers and passive sensors hear the waves, then everything moves.
+    // Built-in systems: antenna_receiver, esm, elint, geolocation, signal_processing, trajectory, movement, towed_decoy
+    Reception,
+}
+
+/// Collects the built-in systems and any user systems, see `Simulation::builder`
+pub struct SimulationBuilder<'a, 'b> {
+    transmission: DispatcherBuilder<'a, 'b>,
+    illumination: DispatcherBuilder<'a, 'b>,
+    reflection: DispatcherBuilder<'a, 'b>,
+    reception: DispatcherBuilder<'a, 'b>,
+}
+
+impl<'a, 'b> SimulationBuilder<'a, 'b> {
+    pub fn new() -> Self {
+        SimulationBuilder{
+            transmission: DispatcherBuilder::new()
+            .with(GeodeticSystem, "geodetic", &[])
+            .with(EccmSystem, "eccm", &[])
+            .with(TransmitSignal, "transmit_signal", &["geodetic", "eccm"]),
+
+            illumination: DispatcherBuilder::new()
+            .with(InteractionDetection, "radar_sensing", &[])
+            .with(ClutterSystem, "clutter", &[])
+            .with(WeatherClutterSystem, "weather_clutter", &[]),
+
+            reflection: DispatcherBuilder::new()
+            .with(DopplerShiftSystem, "doppler_shift", &[])
+            .with(RCSSystem, "rcs_system", &[])
+            .with(JammingSystem, "jamming_system", &[])
+            .with(StandOffJammingSystem, "stand_off_jamming", &[])
+            .with(JammingAnalysisSystem, "jamming_analysis", &[])
+            .with(ChaffSystem, "chaff", &["doppler_shift", "rcs_system"])
+            .with(DispenserSystem, "dispenser", &[])
+            .with(DeceptionJammingSystem, "deception_jamming", &["doppler_shift"])
+            .with(ReflectionSystem, "reflection_creation", &["jamming_system", "stand_off_jamming", "doppler_shift", "rcs_system", "chaff", "dispenser", "deception_jamming"]),
+
+            reception: DispatcherBuilder::new()
+            .with(AntennaReceiverSystem, "antenna_receiver", &[])
+            .with(EsmSystem, "esm", &[])
+            .with(ElintSystem, "elint", &["esm"])
+            .with(GeolocationSystem, "geolocation", &["esm"])
+            .with(SignalProcessingSystem, "signal_processing", &["antenna_receiver"])
+            .with(TrajectorySystem, "trajectory", &[])
+            .with(Movement, "movement", &["trajectory"])
+            .with(TowedDecoySystem, "towed_decoy", &["movement"]),
+        }
+    }
+
+    /// Adds a user system to a stage. Dependencies name systems added earlier to the same stage, built-in or not.
+    pub fn with<S>(mut self, stage: Stage, system: S, name: &str, dependencies: &[&str]) -> Self
+    where S: for<'c> System<'c> + Send + 'a {
+        let builder = match stage {
+            Stage::Transmission => &mut self.transmission,
+            Stage::Illumination => &mut self.illumination,
+            Stage::Reflection => &mut self.reflection,
+            Stage::Reception => &mut self.reception,
+        };
+        builder.add(system, name, dependencies);
+        self
+    }
+
+    pub fn build(self) -> Simulation<'a, 'b> {
+        let mut world = World::new();
+        register_components(&mut world);
+        let mut dispatchers = vec![
+            self.transmission.build(),
+            self.illumination.build(),
+            self.reflection.build(),
+            self.reception.build(),
+            // Waves live until every receiver has heard them and the clock only moves once the tick is over
+            DispatcherBuilder::new()
+            .with(WaveCleanupSystem, "wave_cleanup", &[])
+            .with(ClockSystem, "clock", &[]).build(),
+        ];
+        for dispatcher in dispatchers.iter_mut() {
+            dispatcher.setup(&mut world);
+        }
+        Simulation{world, dispatchers}
+    }
+}
+
+impl<'a, 'b> Default for SimulationBuilder<'a, 'b> {
+    fn default() -> Self {
+        SimulationBuilder::new()
+    }
+}
+
+/// A world with every component registered and the systems run in stage order each tick
+pub struct Simulation<'a, 'b> {
+    pub world: World,
+    dispatchers: Vec<Dispatcher<'a, 'b>>,
+}
+
+impl<'a, 'b> Simulation<'a, 'b> {
+    /// A simulation with only the built-in systems
+    pub fn new() -> Self {
+        SimulationBuilder::new().build()
+    }
+
+    pub fn builder() -> SimulationBuilder<'a, 'b> {
+        SimulationBuilder::new()
+    }
+
+    /// Runs one tick of `SimulationTime::delta` seconds through every stage
+    pub fn step(&mut self) {
+        for dispatcher in self.dispatchers.iter_mut() {
+            dispatcher.dispatch(&self.world);
+            self.world.maintain();
+        }
+    }
+
+    /// Steps for `duration` seconds, rounded to a whole number of ticks, and returns the number of ticks run
+    pub fn run_for(&mut self, duration: f32) -> u32 {
+        let delta = self.world.read_resource::<SimulationTime>().delta;
+        let ticks = if delta > 0.0 { (duration / delta).round().max(0.0) as u32 } else { 0 };
+        for _ in 0..ticks {
+            self.step();
+        }
+        ticks
+    }
+
+    /// Seconds simulated so far
+    pub fn elapsed(&self) -> f32 {
+        self.world.read_resource::<SimulationTime>().elapsed
+    }
+}
+
+impl<'a, 'b> Default for Simulation<'a, 'b> {
+    fn default() -> Self {
+        Simulation::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functions::{load_scenario, populate_world, C};
+
+    // Records the stage each hook ran in, along with the number of waves alive at the time
+    #[derive(Default)]
+    struct Log {
+        entries: Vec<(Stage, usize)>,
+    }
+
+    struct Hook(Stage);
+    impl<'a> System<'a> for Hook {
+        type SystemData = (Write<'a, Log>, ReadStorage<'a, EMWave>);
+
+        fn run(&mut self, (mut log, waves): Self::SystemData) {
+            log.entries.push((self.0, waves.count()));
+        }
+    }
+
+    fn radar(world: &mut World) -> Entity {
+        world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(Antenna{
+            frequency: 1.0e9,
+            gain: 100.0,
+            power: 1000.0,
+            wavelength: C / 1.0e9,
+            azimuth_beam_width: 10.0,
+            elevation_beam_width: 10.0
+        })
+        .with(Scan::Fixed)
+        .with(Receiver::default())
+        .build()
+    }
+
+    #[test]
+    fn test_stage_order() {
+        let mut simulation = Simulation::builder()
+        .with(Stage::Reception, Hook(Stage::Reception), "reception_hook", &["antenna_receiver"])
+        .with(Stage::Reflection, Hook(Stage::Reflection), "reflection_hook", &["reflection_creation"])
+        .with(Stage::Illumination, Hook(Stage::Illumination), "illumination_hook", &[])
+        .with(Stage::Transmission, Hook(Stage::Transmission), "transmission_hook", &["transmit_signal"])
+        .build();
+        radar(&mut simulation.world);
+        simulation.step();
+
+        let log = simulation.world.read_resource::<Log>();
+        let stages: Vec<Stage> = log.entries.iter().map(|(stage, _)| *stage).collect();
+        assert_eq!(stages, vec![Stage::Transmission, Stage::Illumination, Stage::Reflection, Stage::Reception]);
+        // The radar's wave only exists once the transmission stage has been maintained
+        assert_eq!(log.entries[0].1, 0);
+        assert_eq!(log.entries[1].1, 1);
+        assert_eq!(log.entries[3].1, 1);
+    }
+
+    #[test]
+    fn test_step() {
+        let mut simulation = Simulation::new();
+        let radar = radar(&mut simulation.world);
+        // Given only in latitude / longitude, so it gets its Position from the geodetic system
+        let geodetic = simulation.world.create_entity()
+        .with(GeodeticPosition{latitude: 0.01, longitude: 0.0, altitude: 1000.0})
+        .build();
+
+        simulation.step();
+        let positions = simulation.world.read_storage::<Position>();
+        let pos = positions.get(geodetic).unwrap();
+        assert!((pos.y - 1105.7).abs() < 1.0 && pos.x.abs() < 1e-3 && (pos.z - 1000.0).abs() < 1.0);
+        // Waves are gone at the end of the tick, and the clock has moved on by one time step
+        assert_eq!(simulation.world.read_storage::<EMWave>().count(), 0);
+        assert_eq!(simulation.elapsed(), 1.0);
+        assert!(simulation.world.read_storage::<Receiver>().get(radar).is_some());
+    }
+
+    #[test]
+    fn test_run_for() {
+        let mut simulation = Simulation::new();
+        simulation.world.insert(SimulationTime{delta: 0.5, elapsed: 0.0});
+        radar(&mut simulation.world);
+
+        assert_eq!(simulation.run_for(2.0), 4);
+        assert_eq!(simulation.elapsed(), 2.0);
+        assert_eq!(simulation.run_for(0.0), 0);
+        assert_eq!(simulation.elapsed(), 2.0);
+    }
+
+    #[test]
+    fn test_scenario() {
+        // Counts the signals received over the run
+        #[derive(Default)]
+        struct Signals(usize);
+        struct Counter;
+        impl<'a> System<'a> for Counter {
+            type SystemData = (Write<'a, Signals>, ReadStorage<'a, Receiver>);
+
+            fn run(&mut self, (mut signals, receivers): Self::SystemData) {
+                signals.0 += receivers.join().map(|receiver| receiver.signals.len()).sum::<usize>();
+            }
+        }
+
+        let mut simulation = Simulation::builder()
+        .with(Stage::Reception, Counter, "counter", &["signal_processing"])
+        .build();
+        let names = populate_world(&mut simulation.world, load_scenario("scenarios/single_radar.json").unwrap()).unwrap();
+        // The beam steps half a beam width a tick and sweeps past the target after 25 s
+        assert_eq!(simulation.run_for(30.0), 30);
+        assert!(simulation.world.read_resource::<Signals>().0 > 0);
+        let positions = simulation.world.read_storage::<Position>();
+        assert_ne!(positions.get(names["target"]).unwrap().x, 100.0);
+    }
+}
@@ -18,3 +18,105 @@ pub use target_illumination::TargetIllumination;
 
 mod velocity;
 pub use velocity::Velocity;
+
+mod simulation_time;
+pub use simulation_time::SimulationTime;
+
+mod trajectory;
+pub use trajectory::{Trajectory, Waypoint};
+
+mod acceleration;
+pub use acceleration::Acceleration;
+
+mod motion_model;
+pub use motion_model::MotionModel;
+
+mod simulation_rng;
+pub use simulation_rng::SimulationRng;
+
+mod orientation;
+pub use orientation::Orientation;
+
+mod boresight;
+pub use boresight::Boresight;
+
+mod geodetic_position;
+pub use geodetic_position::GeodeticPosition;
+
+mod geodetic_origin;
+pub use geodetic_origin::GeodeticOrigin;
+
+mod earth_model;
+pub use earth_model::EarthModel;
+
+mod terrain;
+pub use terrain::Terrain;
+
+mod weather;
+pub use weather::Weather;
+
+mod surface;
+pub use surface::{Surface, SurfaceKind, Polarization};
+
+mod clutter;
+pub use clutter::{Clutter, ClutterModel, ClutterStatistics, TerrainType};
+
+mod rain_cell;
+pub use rain_cell::RainCell;
+
+mod waveform;
+pub use waveform::Waveform;
+
+mod signal_processor;
+pub use signal_processor::{SignalProcessor, MtiFilter};
+
+mod receiver;
+pub use receiver::{Receiver, ReceivedSignal};
+
+mod jammer;
+pub use jammer::{Jammer, JammingMode, NoiseJamming};
+
+mod jamming_report;
+pub use jamming_report::{JammingReport, JammingAssessment};
+
+mod deception_jammer;
+pub use deception_jammer::{DeceptionJammer, FalseTarget};
+
+mod stand_off_jammer;
+pub use stand_off_jammer::{StandOffJammer, Victim, JammerTarget};
+
+mod chaff;
+pub use chaff::Chaff;
+
+mod decoy;
+pub use decoy::TowedDecoy;
+
+mod dispenser;
+pub use dispenser::{Dispenser, DispenseTrigger, Expendable};
+
+mod eccm;
+pub use eccm::{Eccm, FrequencyAgility, JamStrobe};
+
+mod esm_receiver;
+pub use esm_receiver::{EsmReceiver, Intercept};
+
+mod emitter_library;
+pub use emitter_library::{EmitterLibrary, EmitterEntry};
+
+mod elint;
+pub use elint::{Elint, Pdw, EmitterTrack};
+
+mod geolocation;
+pub use geolocation::{Geolocation, GeoMeasurement, EmitterFix, GeolocationReport};
+
+mod wave_source;
+pub use wave_source::WaveSource;
+
+mod bistatic;
+pub use bistatic::Bistatic;
+
+mod scan;
+pub use scan::Scan;
+
+mod scenario;
+pub use scenario::{AntennaSpec, RcsSpec, RadarSpec, TargetSpec, VictimSpec, StandOffSpec, JammerSpec, RainCellSpec, EnvironmentSpec, Scenario};
@@ -0,0 +1,8 @@
+// m/s^2
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Acceleration {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
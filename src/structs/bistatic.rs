@@ -0,0 +1,8 @@
+use specs::Entity;
+
+/// Makes an entity with an Antenna a receive-only bistatic radar, listening for echoes of an illuminator's waves
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Bistatic {
+    pub illuminator: Entity,
+}
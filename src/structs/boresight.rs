@@ -0,0 +1,7 @@
+/// Antenna pointing relative to the platform's body frame
+#[derive(PartialEq, PartialOrd)]
+#[derive(Debug)]
+pub struct Boresight {
+    pub azimuth: f32,       // degrees, counter-clockwise from the platform's nose
+    pub elevation: f32,     // degrees, above the platform's wings
+}
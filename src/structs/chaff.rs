@@ -0,0 +1,13 @@
+/// Chaff cloud dispensed as its own entity. It blooms to full size, then decays as the dipoles disperse,
+/// while falling and drifting with the wind.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Chaff {
+    pub max_rcs: f32,           // m^2, fully bloomed cross section
+    pub bloom_time: f32,        // seconds, time constant of the bloom
+    pub decay_time: f32,        // seconds, time constant of the decay
+    pub fall_rate: f32,         // m/s
+    pub doppler_spread: f32,    // Hz, standard deviation of the dipoles' Doppler
+    pub lifetime: f32,          // seconds before the cloud is removed
+    pub age: f32,               // seconds since it was dispensed
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum TerrainType {
+    Desert,
+    Farmland,
+    WoodedHills,
+    Mountains,
+    Urban,
+    // Gamma in dB
+    Custom(f32),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum ClutterModel {
+    None,
+    // Land clutter with sigma0 = gamma * sin(grazing angle)
+    ConstantGamma {
+        terrain: TerrainType,
+    },
+    // Georgia Institute of Technology sea clutter model
+    GitSea {
+        sea_state: f32,
+        wind_direction: f32,    // degrees, direction the wind blows from
+    },
+}
+
+/// Amplitude statistics of the clutter power in each cell, all normalized to a mean of one
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum ClutterStatistics {
+    // Always the mean clutter power
+    Mean,
+    Rayleigh,
+    Weibull {
+        shape: f32,
+    },
+    KDistribution {
+        shape: f32,
+    },
+}
+
+/// World resource configuring clutter generation for every radar beam
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct Clutter {
+    pub model: ClutterModel,
+    pub statistics: ClutterStatistics,
+    pub range_resolution: f32,  // meters, depth of each clutter cell
+    pub max_range: f32,         // meters
+}
+
+impl Default for Clutter {
+    fn default() -> Self {
+        Clutter{
+            model: ClutterModel::None,
+            statistics: ClutterStatistics::Rayleigh,
+            range_resolution: 150.0,
+            max_range: 50_000.0
+        }
+    }
+}
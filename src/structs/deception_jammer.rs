@@ -0,0 +1,22 @@
+/// DRFM repeater that answers illuminations with false echoes.
+/// The pull-off echo holds on the skin return, then walks away in range and Doppler before starting over.
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct DeceptionJammer {
+    pub effective_rcs: f32,         // m^2, repeater gain expressed as the cross section it mimics
+    pub hold_time: f32,             // seconds on the skin return before walking off
+    pub range_walk_rate: f32,       // m/s, range gate pull-off
+    pub max_range_offset: f32,      // meters
+    pub doppler_walk_rate: f32,     // Hz/s, velocity gate pull-off
+    pub max_doppler_offset: f32,    // Hz
+    pub false_targets: Vec<f32>,    // meters, range offsets of additional false targets, replayed on the repeater's estimate of the PRI
+    pub engaged_time: f32,          // seconds the current pull-off cycle has been illuminated
+}
+
+/// Marks an EMWave as a false echo from a repeater, offset in range from the jammer carrying it
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub struct FalseTarget {
+    pub range_offset: f32,  // meters, negative when the false echo leads the jammer
+    pub predicted: bool,    // timed off the radar's earlier pulses rather than repeated from the one just received
+}
@@ -0,0 +1,9 @@
+use specs::Entity;
+
+/// Decoy towed on a cable behind another entity
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct TowedDecoy {
+    pub tow: Entity,
+    pub cable_length: f32,  // meters
+}
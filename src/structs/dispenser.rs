@@ -0,0 +1,30 @@
+use super::Chaff;
+
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub enum DispenseTrigger {
+    // Whenever a radar illuminates the dispensing entity
+    Illuminated,
+    // Once the simulation reaches a time, in seconds
+    AtTime(f32),
+}
+
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub enum Expendable {
+    Chaff(Chaff),
+    // An isotropic rcs in m^2, and the effective rcs of an optional repeater
+    TowedDecoy{rcs: f32, cable_length: f32, repeater_rcs: Option<f32>},
+    FreeDecoy{rcs: f32, speed: f32, repeater_rcs: Option<f32>},
+}
+
+/// Launches expendables from an entity when its trigger fires, at most once every `interval` seconds
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Dispenser {
+    pub trigger: DispenseTrigger,
+    pub expendable: Expendable,
+    pub remaining: u32,
+    pub interval: f32,                  // seconds
+    pub last_dispensed: Option<f32>,    // simulation time, seconds
+}
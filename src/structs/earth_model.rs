@@ -0,0 +1,15 @@
+use serde::{Deserialize};
+
+/// World resource describing the earth used for line of sight
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct EarthModel {
+    pub radius: f32,        // meters
+    pub k_factor: f32,      // effective earth radius factor for atmospheric refraction
+}
+
+impl Default for EarthModel {
+    fn default() -> Self {
+        EarthModel{radius: 6371000.0, k_factor: 4.0 / 3.0}
+    }
+}
@@ -0,0 +1,31 @@
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub enum FrequencyAgility {
+    None,
+    // Every pulse hops across the band, so a jammer has to spread over all of it
+    PerPulse{center: f32, bandwidth: f32},
+    // The antenna retunes to a random frequency in the band at the start of each tick
+    PerDwell{center: f32, bandwidth: f32},
+}
+
+/// Electronic counter-countermeasures fitted to a radar. Radars with ECCM also model their receive sidelobes.
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Eccm {
+    pub agility: FrequencyAgility,
+    pub sidelobe_level: f32,            // linear, sidelobe gain relative to the main beam
+    pub sidelobe_blanking: bool,        // drop repeater and other radars' pulses that are stronger in the guard channel
+    pub guard_level: f32,               // linear, gain of the blanking guard channel relative to the main beam
+    pub cancellation_channels: u32,     // auxiliary channels, each can cancel one sidelobe jammer
+    pub cancellation_ratio: f32,        // linear, suppression of each cancelled jammer
+    pub prf_jitter: bool,               // stops repeaters predicting the next pulse
+    pub home_on_jam: bool,              // report the bearing of each jammer received
+}
+
+/// Bearing to a jammer measured by a radar homing on jam
+#[derive(Debug, Clone)]
+#[derive(PartialEq, PartialOrd)]
+pub struct JamStrobe {
+    pub angle: f32,     // degrees
+    pub power: f32,     // Watts of noise from this direction
+}
@@ -0,0 +1,37 @@
+/// Pulse descriptor word, the measurements made on a single intercepted pulse
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Pdw {
+    pub time_of_arrival: f32,   // seconds after the start of the tick
+    pub angle: f32,             // degrees
+    pub frequency: f32,         // Hz
+    pub pulse_width: f32,       // seconds
+    pub power: f32,             // Watts
+}
+
+/// Emitter built up from deinterleaved pulse trains, named when it matches the emitter library
+#[derive(Debug, Clone)]
+#[derive(PartialEq, PartialOrd)]
+pub struct EmitterTrack {
+    pub name: Option<String>,
+    pub angle: f32,         // degrees
+    pub frequency: f32,     // Hz
+    pub pri: f32,           // seconds
+    pub pulse_width: f32,   // seconds
+    pub updates: u32,       // ticks the emitter has been seen in
+    pub last_seen: f32,     // simulation time, seconds
+}
+
+/// ELINT processing attached to an entity with an EsmReceiver.
+/// Each tick the intercepts become PDWs, which are deinterleaved and matched against the EmitterLibrary.
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Elint {
+    pub dwell: f32,             // seconds of pulses collected per intercept
+    pub pri_tolerance: f32,     // seconds, largest TOA error accepted in a pulse sequence
+    pub min_pulses: usize,      // shortest sequence accepted as a pulse train
+    pub angle_gate: f32,        // degrees, largest bearing change for a train to update a track
+    pub track_timeout: f32,     // seconds before an unseen track is dropped
+    pub pdws: Vec<Pdw>,
+    pub tracks: Vec<EmitterTrack>,
+}
@@ -4,6 +4,7 @@ pub struct EMWave {
     pub power: f32,
     pub wavelength: f32,
     pub frequency: f32,
+    pub doppler: f32,           // Hz, shift picked up from moving reflectors, carried apart from the carrier
     pub azimuth_width: f32,     // Degrees
     pub elevation_width: f32
 }
\ No newline at end of file
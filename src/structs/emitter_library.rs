@@ -0,0 +1,20 @@
+use serde::{Deserialize};
+
+/// Parameter ranges of one known emitter
+#[derive(Debug, Clone, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub struct EmitterEntry {
+    pub name: String,
+    pub min_frequency: f32,     // Hz
+    pub max_frequency: f32,     // Hz
+    pub min_pri: f32,           // seconds
+    pub max_pri: f32,           // seconds
+    pub min_pulse_width: f32,   // seconds
+    pub max_pulse_width: f32,   // seconds
+}
+
+/// World resource holding the emitters ELINT processing can identify
+#[derive(Debug, Default, Deserialize)]
+pub struct EmitterLibrary {
+    pub emitters: Vec<EmitterEntry>,
+}
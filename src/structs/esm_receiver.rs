@@ -0,0 +1,25 @@
+/// A single emission picked up by a passive receiver, as measured by it
+#[derive(Debug, Clone)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Intercept {
+    pub angle: f32,                 // degrees, measured angle of arrival
+    pub frequency: f32,             // Hz, as received, including the receiver's own Doppler
+    pub doppler: f32,               // Hz, shift from the receiver's own motion, as cross-ambiguity processing measures it
+    pub pri: Option<f32>,           // seconds, mean pulse repetition interval, None for continuous emissions
+    pub pulse_width: Option<f32>,   // seconds
+    pub power: f32,                 // Watts at the receiver
+    pub time: f32,                  // seconds, simulation time of arrival
+    pub time_of_arrival: f32,       // seconds after the start of the tick, the delay PDW and TDOA processing work from
+}
+
+/// Passive radar warning or ESM receiver. Listens to every emission reaching it without transmitting.
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct EsmReceiver {
+    pub gain: f32,              // linear antenna gain, the same in every direction
+    pub sensitivity: f32,       // Watts, weakest emission detected
+    pub min_frequency: f32,     // Hz
+    pub max_frequency: f32,     // Hz
+    pub df_accuracy: f32,       // degrees, standard deviation of the angle of arrival
+    pub intercepts: Vec<Intercept>,
+}
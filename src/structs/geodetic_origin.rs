@@ -0,0 +1,13 @@
+use super::GeodeticPosition;
+
+/// World resource anchoring the local Cartesian frame. `Position` is east / north / up meters from this point
+#[derive(Debug)]
+pub struct GeodeticOrigin {
+    pub origin: GeodeticPosition,
+}
+
+impl Default for GeodeticOrigin {
+    fn default() -> Self {
+        GeodeticOrigin{origin: GeodeticPosition{latitude: 0.0, longitude: 0.0, altitude: 0.0}}
+    }
+}
@@ -0,0 +1,8 @@
+/// WGS-84 geodetic coordinates. f64 keeps centimeter precision at global scale
+#[derive(PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy)]
+pub struct GeodeticPosition {
+    pub latitude: f64,      // degrees
+    pub longitude: f64,     // degrees
+    pub altitude: f64,      // meters above the ellipsoid
+}
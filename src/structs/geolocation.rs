@@ -0,0 +1,58 @@
+/// One passive measurement of an emitter's position, sensor positions and velocities in meters and m/s
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub enum GeoMeasurement {
+    // Angle of arrival at a sensor, degrees
+    Bearing{sensor: [f32; 3], bearing: f32, sigma: f32},
+    // Arrival time at a sensor minus the arrival time at a reference sensor, seconds
+    Tdoa{sensor: [f32; 3], reference: [f32; 3], difference: f32, sigma: f32},
+    // Doppler at a moving sensor minus the Doppler at a moving reference sensor, Hz
+    Fdoa{sensor: [f32; 3], sensor_velocity: [f32; 3], reference: [f32; 3], reference_velocity: [f32; 3], frequency: f32, difference: f32, sigma: f32},
+}
+
+/// World resource configuring how ESM intercepts from several platforms are combined into fixes
+#[derive(Debug)]
+pub struct Geolocation {
+    pub bearings: bool,
+    pub tdoa: bool,
+    pub fdoa: bool,
+    pub time_accuracy: f32,         // seconds, standard deviation of each arrival time
+    pub frequency_accuracy: f32,    // Hz, standard deviation of each Doppler measurement
+    pub frequency_tolerance: f32,   // Hz, intercepts closer than this are taken to be the same emitter
+    pub emitter_altitude: f32,      // meters, fixes are made in the horizontal plane at this height
+}
+
+impl Default for Geolocation {
+    fn default() -> Self {
+        Geolocation{
+            bearings: true,
+            tdoa: true,
+            fdoa: true,
+            time_accuracy: 50e-9,
+            frequency_accuracy: 1.0,
+            frequency_tolerance: 1e6,
+            emitter_altitude: 0.0
+        }
+    }
+}
+
+/// Estimated emitter position with its 95% error ellipse
+#[derive(Debug, Clone)]
+#[derive(PartialEq, PartialOrd)]
+pub struct EmitterFix {
+    pub frequency: f32,     // Hz
+    pub pri: Option<f32>,   // seconds
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub semi_major: f32,    // meters
+    pub semi_minor: f32,    // meters
+    pub orientation: f32,   // degrees, direction of the major axis
+    pub sensors: usize,     // platforms that contributed
+}
+
+/// World resource holding the emitter fixes made during the last tick
+#[derive(Debug, Default)]
+pub struct GeolocationReport {
+    pub fixes: Vec<EmitterFix>,
+}
@@ -1,9 +1,14 @@
+use super::WaveSource;
+
 #[derive(Debug)]
 #[derive(PartialEq, PartialOrd)]
 pub struct Illumination {
     pub power: f32,
     pub lambda: f32,
     pub frequency: f32,
-    pub angle: f32,
+    pub doppler: f32,       // Hz, shift carried by the wave so far, apart from the carrier
+    pub angle: f32,         // degrees, direction back towards the emitter
+    pub elevation: f32,     // degrees
     pub rcs: f32,
+    pub source: Option<WaveSource>, // tag of the illuminating wave, when known
 }
\ No newline at end of file
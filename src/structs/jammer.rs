@@ -0,0 +1,27 @@
+use serde::{Deserialize};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum JammingMode {
+    // Fixed wide band covering every radar inside it
+    Barrage,
+    // Narrow band set on to the frequency of each illuminating radar
+    Spot,
+}
+
+/// Noise jammer carried by an entity with an Antenna. Answers illuminations with noise instead of a copy of the antenna
+#[derive(Debug, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Jammer {
+    pub mode: JammingMode,
+    pub center_frequency: f32,  // Hz, ignored in spot mode
+    pub bandwidth: f32,         // Hz
+}
+
+/// Marks an EMWave as jammer noise spread over a band, rather than an echo
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub struct NoiseJamming {
+    pub center_frequency: f32,  // Hz
+    pub bandwidth: f32,         // Hz
+}
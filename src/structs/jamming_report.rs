@@ -0,0 +1,16 @@
+use specs::Entity;
+
+#[derive(Debug, Clone)]
+#[derive(PartialEq, PartialOrd)]
+pub struct JammingAssessment {
+    pub radar: Entity,
+    pub range: f32,                 // meters
+    pub jamming_to_signal: f32,     // linear, at the radar's receiver
+    pub burn_through_range: f32,    // meters, where the echo overpowers the jamming
+}
+
+/// A jammer's effectiveness against every radar it is jamming, refreshed each tick
+#[derive(Debug, Default)]
+pub struct JammingReport {
+    pub assessments: Vec<JammingAssessment>,
+}
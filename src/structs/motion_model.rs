@@ -0,0 +1,16 @@
+/// Selects how `Movement` propagates an entity. Entities without one fly at constant velocity
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub enum MotionModel {
+    ConstantVelocity,
+    // Applies the entity's Acceleration component
+    ConstantAcceleration,
+    CoordinatedTurn {
+        turn_rate: f32,     // degrees / s, positive turns counter-clockwise
+    },
+    // First-order Gauss-Markov acceleration, stored in the entity's Acceleration component
+    Singer {
+        tau: f32,           // maneuver time constant, seconds
+        sigma: f32,         // rms maneuver acceleration, m/s^2
+    },
+}
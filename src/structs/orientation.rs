@@ -0,0 +1,9 @@
+/// Platform attitude. Angles follow the same convention as `Position::direction`,
+/// with yaw measured counter-clockwise from the x axis.
+#[derive(PartialEq, PartialOrd)]
+#[derive(Debug)]
+pub struct Orientation {
+    pub yaw: f32,       // degrees
+    pub pitch: f32,     // degrees, nose up is positive
+    pub roll: f32,      // degrees, right wing down is positive
+}
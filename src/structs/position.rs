@@ -1,8 +1,11 @@
-#[derive(PartialEq, PartialOrd)]
+use serde::{Deserialize};
+
+#[derive(PartialEq, PartialOrd, Deserialize)]
 #[derive(Debug)]
 pub struct Position {
     pub x: f32, // meters
     pub y: f32,
     pub z: f32,
-    pub direction: f32,
+    #[serde(default)]
+    pub direction: f32, // degrees, used when there is no Orientation or Boresight
 }
\ No newline at end of file
@@ -0,0 +1,12 @@
+use serde::{Deserialize};
+
+/// A spherical volume of rain centered on the entity's Position
+#[derive(Debug, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub struct RainCell {
+    pub radius: f32,            // meters
+    pub rain_rate: f32,         // mm/h
+    pub wind_x: f32,            // m/s, mean motion of the rain drops
+    pub wind_y: f32,
+    pub velocity_spread: f32,   // m/s, rms turbulence and shear about the mean
+}
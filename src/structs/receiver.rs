@@ -0,0 +1,29 @@
+use super::{JamStrobe, SignalProcessor, Waveform};
+
+#[derive(Debug, Clone)]
+#[derive(PartialEq, PartialOrd)]
+pub struct ReceivedSignal {
+    pub power: f32,             // Watts at the antenna
+    pub range: f32,             // meters, the range sum R_t + R_r for bistatic receivers
+    pub angle: f32,             // degrees, direction the signal arrived from
+    pub frequency: f32,         // Hz
+    pub doppler: f32,           // Hz, offset from the radar's own frequency
+    pub processing_gain: f32,   // power gain of the signal processor at this Doppler
+    pub jamming_to_signal: f32, // in-band noise jamming plus other radars' noise rise, over this signal's power
+    pub bistatic_angle: f32,    // degrees, zero for monostatic echoes
+}
+
+/// Everything a radar's antenna received during the last tick, along with signal processor performance
+#[derive(Debug, Default)]
+pub struct Receiver {
+    pub signals: Vec<ReceivedSignal>,           // the radar's own echoes
+    pub interference: Vec<ReceivedSignal>,      // other radars' direct signals and echoes inside the receiver's band
+    pub interference_targets: Vec<ReceivedSignal>, // false targets from other radars' pulses, at their apparent range
+    pub interference_power: f32,        // Watts, the noise rise from other radars averaged over their duty cycle
+    pub jamming_power: f32,             // Watts of noise jamming inside the receiver's band
+    pub improvement_factor: f32,        // clutter improvement factor, linear
+    pub first_blind_speed: Option<f32>, // m/s
+    pub first_blind_doppler: Option<f32>, // Hz
+    pub processed_for: Option<(SignalProcessor, Waveform)>, // processor and waveform the figures above were worked out for
+    pub jam_strobes: Vec<JamStrobe>,    // jammer bearings, when homing on jam
+}
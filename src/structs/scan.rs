@@ -0,0 +1,15 @@
+use serde::{Deserialize};
+
+/// How an antenna sweeps its beam. Antennas without a Scan step half a beam width every tick.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum Scan {
+    // Half a beam width every tick, whatever the time step
+    Step,
+    // Mechanical rotation at a fixed rate, degrees / s counter-clockwise
+    Circular {
+        rate: f32,
+    },
+    // Stares along its current direction
+    Fixed,
+}
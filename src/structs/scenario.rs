@@ -0,0 +1,122 @@
+use serde::{Deserialize};
+use std::path::PathBuf;
+use super::{Clutter, EarthModel, Jammer, Position, RainCell, Scan, SignalProcessor, Surface, Trajectory, Velocity, Waveform, Weather, RCS};
+
+/// Antenna as an analyst would describe it, the wavelength follows from the frequency
+#[derive(Debug, Clone, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub struct AntennaSpec {
+    pub frequency: f32,             // Hz
+    pub gain_db: f32,               // dBi
+    pub power: f32,                 // Watts
+    pub azimuth_beam_width: f32,    // degrees
+    pub elevation_beam_width: f32,  // degrees
+}
+
+/// Where a target's radar cross section comes from
+#[derive(Debug, Deserialize)]
+pub enum RcsSpec {
+    // Path to an RCS JSON file, relative to the scenario file
+    File(String),
+    // Same cross section from every angle, square meters
+    Constant(f32),
+    Table(RCS),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RadarSpec {
+    pub name: String,
+    pub position: Position,
+    pub antenna: AntennaSpec,
+    pub waveform: Option<Waveform>,
+    pub scan: Option<Scan>,
+    pub signal_processor: Option<SignalProcessor>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TargetSpec {
+    pub name: String,
+    pub position: Position,
+    pub velocity: Option<Velocity>,
+    pub rcs: RcsSpec,
+    pub trajectory: Option<Trajectory>,
+}
+
+/// A radar or a sector for a stand-off jammer to cover, `radar` names one of the scenario's radars
+#[derive(Debug, Clone, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum VictimSpec {
+    Radar{radar: String, priority: f32},
+    Sector{azimuth: f32, width: f32, priority: f32},
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub struct StandOffSpec {
+    #[serde(default)]
+    pub victims: Vec<VictimSpec>,
+    #[serde(default)]
+    pub protected: Vec<String>,     // names of the targets being escorted
+    #[serde(default)]
+    pub escort_priority: f32,
+    pub max_beams: usize,
+}
+
+/// Noise jammer. Without `stand_off` it screens itself and needs an `rcs` to be illuminated.
+#[derive(Debug, Deserialize)]
+pub struct JammerSpec {
+    pub name: String,
+    pub position: Position,
+    pub velocity: Option<Velocity>,
+    pub antenna: AntennaSpec,
+    pub jammer: Jammer,
+    pub rcs: Option<RcsSpec>,
+    pub trajectory: Option<Trajectory>,
+    pub stand_off: Option<StandOffSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RainCellSpec {
+    pub position: Position,
+    #[serde(flatten)]
+    pub cell: RainCell,
+}
+
+/// World resources, each left at its default when missing
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct EnvironmentSpec {
+    pub weather: Weather,
+    pub clutter: Clutter,
+    pub surface: Surface,
+    pub earth: EarthModel,
+    pub terrain: Option<String>,    // DEM file, relative to the scenario file
+    pub rain_cells: Vec<RainCellSpec>,
+}
+
+/// Everything needed to set up and run a simulation, read from a JSON or TOML file
+#[derive(Debug, Deserialize)]
+pub struct Scenario {
+    #[serde(default)]
+    pub name: String,
+    pub duration: f32,                      // seconds of simulated time
+    #[serde(default = "default_time_step")]
+    pub time_step: f32,                     // seconds per tick
+    #[serde(default)]
+    pub seed: u64,
+    pub emitter_library: Option<String>,    // emitter library file, relative to the scenario file
+    #[serde(default)]
+    pub environment: EnvironmentSpec,
+    #[serde(default)]
+    pub radars: Vec<RadarSpec>,
+    #[serde(default)]
+    pub targets: Vec<TargetSpec>,
+    #[serde(default)]
+    pub jammers: Vec<JammerSpec>,
+    #[serde(skip)]
+    pub base_dir: PathBuf,                  // directory relative paths are resolved against
+}
+
+fn default_time_step() -> f32 {
+    1.0
+}
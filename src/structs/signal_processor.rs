@@ -0,0 +1,18 @@
+use serde::{Deserialize};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum MtiFilter {
+    None,
+    TwoPulse,
+    ThreePulse,
+}
+
+/// Receive chain clutter cancellation for a radar with a Waveform
+#[derive(Debug, Clone, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub struct SignalProcessor {
+    pub mti: MtiFilter,
+    pub doppler_filters: u32,   // size of the pulse-Doppler filter bank, 0 for none
+    pub clutter_spread: f32,    // Hz, rms width of the clutter spectrum used for the improvement factor
+}
@@ -0,0 +1,20 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// World resource holding the seeded random number generator shared by stochastic systems
+#[derive(Debug)]
+pub struct SimulationRng {
+    pub rng: StdRng,
+}
+
+impl SimulationRng {
+    pub fn new(seed: u64) -> Self {
+        SimulationRng{rng: StdRng::seed_from_u64(seed)}
+    }
+}
+
+impl Default for SimulationRng {
+    fn default() -> Self {
+        SimulationRng::new(0)
+    }
+}
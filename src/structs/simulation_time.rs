@@ -0,0 +1,12 @@
+/// World resource holding the simulation time step and the time elapsed so far
+#[derive(Debug)]
+pub struct SimulationTime {
+    pub delta: f32,     // seconds advanced each tick
+    pub elapsed: f32,   // seconds since the start of the simulation
+}
+
+impl Default for SimulationTime {
+    fn default() -> Self {
+        SimulationTime{delta: 1.0, elapsed: 0.0}
+    }
+}
@@ -0,0 +1,28 @@
+use specs::Entity;
+
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub enum JammerTarget {
+    // Points at a radar entity's Position wherever it is
+    Radar(Entity),
+    // Floods a fixed sector, azimuth and width in degrees
+    Sector{azimuth: f32, width: f32},
+}
+
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Victim {
+    pub target: JammerTarget,
+    pub priority: f32,  // relative share of the jammer's power
+}
+
+/// Jammer that transmits continuously rather than answering its own illuminations.
+/// Used with a Jammer and an Antenna. Escorts also jam every radar illuminating the entities they protect.
+#[derive(Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub struct StandOffJammer {
+    pub victims: Vec<Victim>,
+    pub protected: Vec<Entity>,
+    pub escort_priority: f32,   // share given to each radar illuminating a protected entity
+    pub max_beams: usize,       // simultaneous beams the jammer antenna can form
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum SurfaceKind {
+    // Free space propagation, no surface reflection
+    None,
+    Sea,
+    Land,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub enum Polarization {
+    Horizontal,
+    Vertical,
+}
+
+/// World resource describing the earth's surface for two-ray multipath
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct Surface {
+    pub kind: SurfaceKind,
+    pub roughness: f32,             // meters, rms surface height
+    pub polarization: Polarization,
+    pub max_grazing_angle: f32,     // degrees, steeper paths are treated as free space
+}
+
+impl Default for Surface {
+    fn default() -> Self {
+        Surface{
+            kind: SurfaceKind::None,
+            roughness: 0.0,
+            polarization: Polarization::Horizontal,
+            max_grazing_angle: 10.0
+        }
+    }
+}
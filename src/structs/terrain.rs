@@ -0,0 +1,12 @@
+/// World resource holding a digital elevation model on a regular grid in the local `Position` frame.
+/// An empty grid is flat terrain that never masks anything.
+#[derive(Debug, Default)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Terrain {
+    pub ncols: usize,
+    pub nrows: usize,
+    pub x_origin: f32,      // meters, x of the south west cell center
+    pub y_origin: f32,      // meters, y of the south west cell center
+    pub cell_size: f32,     // meters
+    pub heights: Vec<f32>,  // meters, row major starting from the southern row
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize};
+
+#[derive(Debug, Clone, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Waypoint {
+    pub x: f32,         // meters
+    pub y: f32,
+    pub z: f32,
+    pub speed: f32,     // m/s flown on the leg towards this waypoint
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Trajectory {
+    pub waypoints: Vec<Waypoint>,
+    #[serde(default)]
+    pub current: usize,             // index of the waypoint being flown to
+    pub max_turn_rate: Option<f32>, // degrees / s
+    pub max_g: Option<f32>,         // lateral acceleration limit in g's
+    pub capture_radius: f32,        // meters, distance at which a waypoint counts as reached
+    #[serde(default)]
+    pub cyclic: bool,               // start over at the first waypoint when the last is reached (orbits)
+}
@@ -1,5 +1,8 @@
+use serde::{Deserialize};
+
 // m/s
-#[derive(Debug)]
+#[derive(Debug, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
 pub struct Velocity {
     pub x: f32,
     pub y: f32,
@@ -0,0 +1,12 @@
+use specs::Entity;
+
+/// Tags an EMWave with the entity that transmitted it and, for echoes, the entity that reflected it.
+/// Receivers use it to tell their own echoes from other radars' signals.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub struct WaveSource {
+    pub emitter: Entity,
+    pub waveform_id: u32,           // Waveform id of the transmission, zero for continuous waves
+    pub reflector: Option<Entity>,  // last entity the wave bounced off
+    pub bounces: u32,               // zero for the direct wave
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize};
+
+/// Pulsed waveform transmitted by a radar
+#[derive(Debug, Clone, Deserialize)]
+#[derive(PartialEq, PartialOrd)]
+pub struct Waveform {
+    #[serde(default)]
+    pub id: u32,            // tags every wave transmitted with this waveform
+    pub prf: f32,           // Hz, mean pulse repetition frequency
+    pub pulse_width: f32,   // seconds
+    pub pulses: u32,        // pulses per dwell
+    #[serde(default)]
+    pub stagger: Vec<f32>,  // relative PRI ratios cycled pulse to pulse, empty for a constant PRF
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize};
+
+/// World resource describing the atmosphere waves propagate through.
+/// The default is a standard atmosphere with no rain or fog.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct Weather {
+    pub water_vapour_density: f32,  // g/m^3
+    pub rain_rate: f32,             // mm/h
+    pub fog_density: f32,           // liquid water, g/m^3
+    pub temperature: f32,           // degrees Celsius
+    pub wind_x: f32,                // m/s, carries chaff and other drifting objects
+    pub wind_y: f32,                // m/s
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Weather{
+            water_vapour_density: 7.5,
+            rain_rate: 0.0,
+            fog_density: 0.0,
+            temperature: 15.0,
+            wind_x: 0.0,
+            wind_y: 0.0
+        }
+    }
+}
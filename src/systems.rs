@@ -24,4 +24,55 @@ mod transmit_signal;
 pub use transmit_signal::TransmitSignal;
 
 mod jamming;
-pub use jamming::JammingSystem;
\ No newline at end of file
+pub use jamming::JammingSystem;
+
+mod trajectory;
+pub use trajectory::TrajectorySystem;
+
+mod geodetic;
+pub use geodetic::GeodeticSystem;
+
+mod clutter;
+pub use clutter::ClutterSystem;
+
+mod weather_clutter;
+pub use weather_clutter::WeatherClutterSystem;
+
+mod signal_processing;
+pub use signal_processing::SignalProcessingSystem;
+
+mod jamming_analysis;
+pub use jamming_analysis::JammingAnalysisSystem;
+
+mod deception_jamming;
+pub use deception_jamming::DeceptionJammingSystem;
+
+mod stand_off_jamming;
+pub use stand_off_jamming::StandOffJammingSystem;
+
+mod chaff;
+pub use chaff::ChaffSystem;
+
+mod dispenser;
+pub use dispenser::DispenserSystem;
+
+mod towed_decoy;
+pub use towed_decoy::TowedDecoySystem;
+
+mod clock;
+pub use clock::ClockSystem;
+
+mod eccm;
+pub use eccm::EccmSystem;
+
+mod esm;
+pub use esm::EsmSystem;
+
+mod elint;
+pub use elint::ElintSystem;
+
+mod geolocation;
+pub use geolocation::GeolocationSystem;
+
+mod wave_cleanup;
+pub use wave_cleanup::WaveCleanupSystem;
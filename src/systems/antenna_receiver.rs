@@ -7,23 +7,187 @@ impl<'a> System<'a> for AntennaReceiverSystem {
         ReadStorage<'a, Position>,
         ReadStorage<'a, EMWave>,
         ReadStorage<'a, Antenna>,
+        Read<'a, EarthModel>,
+        Read<'a, Terrain>,
+        Read<'a, Weather>,
+        Read<'a, Surface>,
+        ReadStorage<'a, RainCell>,
+        WriteStorage<'a, Receiver>,
+        ReadStorage<'a, NoiseJamming>,
+        ReadStorage<'a, Waveform>,
+        ReadStorage<'a, Eccm>,
+        ReadStorage<'a, FalseTarget>,
+        ReadStorage<'a, Orientation>,
+        ReadStorage<'a, Boresight>,
+        ReadStorage<'a, WaveSource>,
+        ReadStorage<'a, Bistatic>,
+        ReadStorage<'a, Velocity>,
+        ReadStorage<'a, RCS>,
+        Read<'a, SimulationTime>,
         Entities<'a>,
     );
 
-    fn run(&mut self, (positions, emissions, antennas, entities) : Self::SystemData) {
-        for (_antenna, antenna_pos) in (&antennas, &positions).join() {
-            for(em_entity, em, em_pos) in (&*entities, &emissions, &positions).join() {
+    fn run(&mut self, (positions, emissions, antennas, earth, terrain, weather, surface, rain_cells, mut receivers, noise, waveforms, eccm, false_targets, orientations, boresights, sources, bistatic, velocities, cross_sections, time, entities) : Self::SystemData) {
+        for (radar, antenna, antenna_pos, mut receiver, waveform, eccm, orient, bore, bistatic) in (&*entities, &antennas, &positions, (&mut receivers).maybe(), waveforms.maybe(), eccm.maybe(), orientations.maybe(), boresights.maybe(), bistatic.maybe()).join() {
+            if let Some(receiver) = receiver.as_mut() {
+                receiver.signals.clear();
+                receiver.interference.clear();
+                receiver.interference_targets.clear();
+                receiver.interference_power = 0.0;
+                receiver.jamming_power = 0.0;
+                receiver.jam_strobes.clear();
+            }
+            let pointing = antenna_direction(antenna_pos, orient, bore);
+            let mut sidelobe_jamming: Vec<f32> = Vec::new();
+            let own_waveform = waveform.map_or(0, |wf| wf.id);
+            for(em, em_pos, jamming, false_target, source) in (&emissions, &positions, noise.maybe(), false_targets.maybe(), sources.maybe()).join() {
+                // Bistatic receivers take the echoes of their illuminator's waves, which scatter in every direction
+                let bistatic_echo = match (bistatic, source) {
+                    (Some(bistatic), Some(WaveSource{emitter, reflector: Some(reflector), ..})) if *emitter == bistatic.illuminator => {
+                        positions.get(*emitter).map(|tx_pos| (*emitter, tx_pos, *reflector))
+                    },
+                    _ => None,
+                };
+                // A radar's own echoes are signals, other radars' waves are interference.
+                // Untagged waves come from systems that do not track sources and are treated as echoes.
+                let (own, ignored) = match (source, bistatic) {
+                    (None, _) => (true, false),
+                    (Some(_), Some(_)) => (bistatic_echo.is_some(), false),
+                    (Some(source), None) if source.emitter == radar => {
+                        // The radar's own transmission, or an echo of a waveform it is no longer using
+                        let echo = source.bounces > 0 && source.waveform_id == own_waveform;
+                        (echo, !echo)
+                    },
+                    (Some(_), None) => (false, false),
+                };
+                if ignored && jamming.is_none() {
+                    continue;
+                }
                 let angle = incident_angle(em_pos, antenna_pos);
-                if check_illumination(em.azimuth_width, em_pos.direction, angle) {
-                    println!("Radar detected emission from angle: {}", antenna_pos.direction);
-                    let range = calculate_range(&em_pos, &antenna_pos);
-                    let _power = em.power / (4.0 * std::f32::consts::PI * range.powi(2));
+                if (bistatic_echo.is_some() || check_illumination(em.azimuth_width, em_pos.direction, angle))
+                    && line_of_sight(em_pos, antenna_pos, &earth) && terrain_clear(&terrain, em_pos, antenna_pos) {
+                    let range = slant_range(em_pos, antenna_pos);
+                    let density = em.power * atmospheric_loss(em.frequency, range, &weather)
+                        * rain_cell_loss(em_pos, antenna_pos, em.frequency, (&rain_cells, &positions).join())
+                        * propagation_factor(em_pos, antenna_pos, em.wavelength, &surface).powi(2)
+                        / (4.0 * std::f32::consts::PI * range.powi(2));
                     let _time = range / (3.0 * (100000000.0));
+                    if let Some(receiver) = receiver.as_mut() {
+                        // Collected through the antenna's effective aperture, G lambda^2 / 4 pi
+                        let aperture = antenna.gain * antenna.wavelength.powi(2) / (4.0 * std::f32::consts::PI);
+                        let arrival = incident_angle(antenna_pos, em_pos);
+                        // Only radars with ECCM model their sidelobes, the rest receive everything on the main beam
+                        let sidelobe = match eccm {
+                            Some(eccm) if !check_illumination(antenna.azimuth_beam_width, pointing, arrival) => Some(eccm),
+                            _ => None,
+                        };
+                        let pattern = sidelobe.map_or(1.0, |eccm| eccm.sidelobe_level);
+                        match jamming {
+                            // Noise only counts where the jamming band overlaps the radar's band
+                            Some(jamming) => {
+                                let fraction = match eccm.map(|eccm| eccm.agility) {
+                                    Some(FrequencyAgility::PerPulse{center, bandwidth}) => {
+                                        agile_in_band_fraction(jamming, center, bandwidth, radar_bandwidth(waveform))
+                                    },
+                                    _ => in_band_fraction(jamming, antenna.frequency, radar_bandwidth(waveform)),
+                                };
+                                let power = density * aperture * pattern * fraction;
+                                if sidelobe.is_some() {
+                                    sidelobe_jamming.push(power);
+                                } else {
+                                    receiver.jamming_power += power;
+                                }
+                                if eccm.is_some_and(|eccm| eccm.home_on_jam) && power > 0.0 {
+                                    receiver.jam_strobes.push(JamStrobe{angle: arrival, power});
+                                }
+                            },
+                            // Blanking drops repeater and other radars' pulses that are stronger in the guard channel than in the main channel
+                            None if (false_target.is_some() || !own)
+                                && sidelobe.is_some_and(|eccm| eccm.sidelobe_blanking && eccm.guard_level > pattern) => (),
+                            // A repeater can answer the pulse it just received, but anything it times off earlier pulses misses a jittered PRI
+                            None if false_target.is_some_and(|f| f.predicted || f.range_offset < 0.0) && eccm.is_some_and(|eccm| eccm.prf_jitter) => (),
+                            None if !own => {
+                                // Another radar's pulses, only the part inside this radar's band gets through
+                                let emitter = source.map(|source| source.emitter);
+                                let interferer = emitter.and_then(|emitter| waveforms.get(emitter));
+                                let fraction = interference_fraction(em.frequency, interferer, antenna.frequency, waveform);
+                                if fraction <= 0.0 {
+                                    continue;
+                                }
+                                let signal = ReceivedSignal{
+                                    power: density * aperture * pattern * fraction,
+                                    range,
+                                    angle: arrival,
+                                    frequency: em.frequency + em.doppler,
+                                    doppler: em.frequency - antenna.frequency + em.doppler,
+                                    processing_gain: 1.0,
+                                    jamming_to_signal: 0.0,
+                                    bistatic_angle: 0.0
+                                };
+                                receiver.interference_power += signal.power * duty_cycle(interferer);
+                                // Pulses arriving during the dwell show up at the time since this radar's last pulse
+                                if let (Some(interferer), Some(waveform)) = (interferer, waveform) {
+                                    let path = emitter.and_then(|emitter| positions.get(emitter)).map_or(0.0, |tx_pos| slant_range(tx_pos, em_pos)) + range;
+                                    let range_bin = C * waveform.pulse_width / 2.0;
+                                    for (apparent_range, hits) in interference_ranges(1.0 / interferer.prf, 1.0 / waveform.prf, path / C, time.elapsed, waveform.pulses, range_bin) {
+                                        // Pulses that land in a bin only some of the time integrate to a fraction of a full echo
+                                        receiver.interference_targets.push(ReceivedSignal{
+                                            power: signal.power * hits as f32 / waveform.pulses as f32,
+                                            range: apparent_range,
+                                            ..signal.clone()
+                                        });
+                                    }
+                                }
+                                receiver.interference.push(signal);
+                            },
+                            None => {
+                                let (scatter, range, frequency, doppler, beta) = match bistatic_echo {
+                                    Some((illuminator, tx_pos, reflector)) => {
+                                        // The echo left the target with its monostatic RCS, rescale to the RCS along the bisector
+                                        let scatter = cross_sections.get(reflector).map_or(1.0, |rcs| {
+                                            let aspect = |(azimuth, elevation): (f32, f32)| match orientations.get(reflector) {
+                                                Some(orient) => body_aspect(orient, azimuth, elevation).0,
+                                                None => azimuth,
+                                            };
+                                            let monostatic = calculate_rcs(aspect((incident_angle(em_pos, tx_pos), elevation_angle(em_pos, tx_pos))), &rcs.angles, &rcs.values);
+                                            let bisector = calculate_rcs(aspect(bisector_direction(tx_pos, em_pos, antenna_pos)), &rcs.angles, &rcs.values);
+                                            if monostatic > 0.0 { bisector / monostatic } else { 0.0 }
+                                        });
+                                        let tx_frequency = antennas.get(illuminator).map_or(em.frequency, |a| a.frequency);
+                                        let shift = velocities.get(reflector).map_or(0.0, |vel| bistatic_doppler(tx_frequency, vel, tx_pos, em_pos, antenna_pos));
+                                        (scatter, bistatic_range(tx_pos, em_pos, antenna_pos), tx_frequency + shift,
+                                            tx_frequency - antenna.frequency + shift, bistatic_angle(tx_pos, em_pos, antenna_pos))
+                                    },
+                                    None => (1.0, range, em.frequency + em.doppler, em.frequency - antenna.frequency + em.doppler, 0.0),
+                                };
+                                // Nothing comes back off a null in the pattern or the bistatic RCS, and a zero power echo has no J/S
+                                let power = density * aperture * pattern * scatter;
+                                if power <= 0.0 {
+                                    continue;
+                                }
+                                receiver.signals.push(ReceivedSignal{
+                                    power,
+                                    range,
+                                    angle: arrival,
+                                    frequency,
+                                    doppler,
+                                    processing_gain: 1.0,
+                                    jamming_to_signal: 0.0,
+                                    bistatic_angle: beta
+                                });
+                            },
+                        }
+                    }
                 }
-            
-                match entities.delete(em_entity) {
-                    Ok(r) => r,
-                    Err(e) => eprintln!("Error!\n {}", e),
+            }
+
+            if let Some(receiver) = receiver.as_mut() {
+                let (channels, ratio) = eccm.map_or((0, 1.0), |eccm| (eccm.cancellation_channels, eccm.cancellation_ratio));
+                receiver.jamming_power += cancel_sidelobe_jamming(sidelobe_jamming, channels, ratio);
+                // Other radars' noise rise competes with the echo the same way noise jamming does
+                let noise_power = receiver.jamming_power + receiver.interference_power;
+                for signal in receiver.signals.iter_mut() {
+                    signal.jamming_to_signal = noise_power / signal.power;
                 }
             }
         }
@@ -49,6 +213,7 @@ impl<'a> System<'a> for AntennaReceiverSystem {
 //                     for illum in illums.illuminations.iter() {
 //                         assert_eq!(illum, &Illumination{
 //                             angle: 225.0,
+//                             elevation: 0.0,
 //                             frequency: 100.0,
 //                             lambda: 100.0,
 //                             rcs: 180.0,
@@ -110,4 +275,399 @@ impl<'a> System<'a> for AntennaReceiverSystem {
 //         // Run test 
 //         tester.run_now(&world);
 //     }
-// }
\ No newline at end of file
+// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eccm() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, Receiver>;
+
+            fn run(&mut self, receivers: Self::SystemData) {
+                for receiver in receivers.join() {
+                    // The skin echoes and the repeat of the current pulse in the main beam get through.
+                    // The sidelobe repeater is blanked, and the leading and replayed false targets are defeated by jitter.
+                    let mut received: Vec<(f32, f32)> = receiver.signals.iter().map(|s| (s.angle, s.range)).collect();
+                    received.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                    assert_eq!(received, vec![(0.0, 5000.0), (0.0, 5500.0), (90.0, 5000.0)]);
+                    assert_eq!(receiver.jam_strobes.len(), 2);
+                    // The stronger sidelobe jammer is cancelled, leaving the weaker one
+                    let aperture = 1.0 / (4.0 * std::f32::consts::PI);
+                    let weak = 1000.0 / (4.0 * std::f32::consts::PI * 2000.0_f32.powi(2)) * aperture * 0.01;
+                    let strong = 4.0 * weak / 100.0;
+                    assert!((receiver.jamming_power / (weak + strong) - 1.0).abs() < 0.01);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<Antenna>();
+
+        let mut sys = AntennaReceiverSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let _radar = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(Antenna{
+            frequency: 1.0e6,
+            gain: 1.0,
+            power: 1.0,
+            wavelength: 1.0,
+            azimuth_beam_width: 10.0,
+            elevation_beam_width: 10.0
+        }).with(Eccm{
+            agility: FrequencyAgility::None,
+            sidelobe_level: 0.01,
+            sidelobe_blanking: true,
+            guard_level: 0.05,
+            cancellation_channels: 1,
+            cancellation_ratio: 100.0,
+            prf_jitter: true,
+            home_on_jam: true
+        }).with(Receiver::default()).build();
+
+        // Waves pointed back at the radar from a position at some bearing and range
+        fn wave(world: &mut World, bearing: f32, range: f32) -> EntityBuilder<'_> {
+            let (sin_b, cos_b) = bearing.to_radians().sin_cos();
+            world.create_entity()
+            .with(Position{x: range * cos_b, y: range * sin_b, z: 10.0, direction: (bearing + 180.0) % 360.0})
+            .with(EMWave{
+                power: 1000.0,
+                wavelength: C / 1.0e6,
+                frequency: 1.0e6,
+                doppler: 0.0,
+                azimuth_width: 20.0,
+                elevation_width: 20.0
+            })
+        }
+        wave(&mut world, 0.0, 5000.0).build();
+        wave(&mut world, 90.0, 5000.0).build();
+        wave(&mut world, 90.0, 4000.0).with(FalseTarget{range_offset: 500.0, predicted: false}).build();
+        wave(&mut world, 0.0, 4000.0).with(FalseTarget{range_offset: -1000.0, predicted: true}).build();
+        wave(&mut world, 0.0, 6000.0).with(FalseTarget{range_offset: 1000.0, predicted: true}).build();
+        wave(&mut world, 0.0, 5500.0).with(FalseTarget{range_offset: 500.0, predicted: false}).build();
+        let noise = NoiseJamming{center_frequency: 1.0e6, bandwidth: 1e6};
+        wave(&mut world, 90.0, 1000.0).with(noise).build();
+        wave(&mut world, 270.0, 2000.0).with(noise).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+    #[test]
+    fn test_bistatic() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, Receiver>;
+
+            fn run(&mut self, receivers: Self::SystemData) {
+                for receiver in receivers.join() {
+                    // Only the echo of the illuminator's wave is taken, the others point away from the receiver
+                    assert_eq!(receiver.signals.len(), 1);
+                    assert!(receiver.interference.is_empty());
+                    let signal = &receiver.signals[0];
+                    assert!((signal.range - 2000.0 * 2.0_f32.sqrt()).abs() < 0.1);
+                    assert!((signal.bistatic_angle - 90.0).abs() < 1e-2);
+                    assert!((signal.doppler + 1.0e9 * 100.0 * 2.0_f32.sqrt() / C).abs() < 1.0);
+                    assert_eq!(signal.angle, 135.0);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<Antenna>();
+
+        let mut sys = AntennaReceiverSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let antenna = || Antenna{
+            frequency: 1.0e9,
+            gain: 100.0,
+            power: 1000.0,
+            wavelength: C / 1.0e9,
+            azimuth_beam_width: 360.0,
+            elevation_beam_width: 90.0
+        };
+        let illuminator = world.create_entity()
+        .with(Position{x: -1000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(antenna())
+        .build();
+        let other = world.create_entity()
+        .with(Position{x: -5000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .build();
+        let _receiver = world.create_entity()
+        .with(Position{x: 1000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(antenna())
+        .with(Bistatic{illuminator})
+        .with(Receiver::default())
+        .build();
+        let target = world.create_entity()
+        .with(Position{x: 0.0, y: 1000.0, z: 10.0, direction: 0.0})
+        .with(Velocity{x: 0.0, y: 100.0, z: 0.0})
+        .with(isotropic_rcs(10.0))
+        .build();
+
+        // Echoes leave the target pointed back at whoever illuminated it
+        let echo = |world: &mut World, emitter: Entity, reflector: Option<Entity>| {
+            world.create_entity()
+            .with(Position{x: 0.0, y: 1000.0, z: 10.0, direction: 225.0})
+            .with(EMWave{
+                power: 1.0,
+                wavelength: C / 1.0e9,
+                frequency: 1.0e9,
+                doppler: 0.0,
+                azimuth_width: 20.0,
+                elevation_width: 20.0
+            }).with(WaveSource{emitter, waveform_id: 0, reflector, bounces: reflector.map_or(0, |_| 1)})
+            .build();
+        };
+        echo(&mut world, illuminator, Some(target));
+        echo(&mut world, other, Some(target));
+        echo(&mut world, illuminator, None);
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+    #[test]
+    fn test_echo_routing() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, Receiver>;
+
+            fn run(&mut self, receivers: Self::SystemData) {
+                for receiver in receivers.join() {
+                    // Own echo and the untagged wave are signals, its own direct wave is ignored
+                    assert_eq!(receiver.signals.len(), 2);
+                    // The other radar's direct wave and its echo are interference
+                    assert_eq!(receiver.interference.len(), 2);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<Antenna>();
+
+        let mut sys = AntennaReceiverSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let radar = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(Antenna{
+            frequency: 1.0e9,
+            gain: 100.0,
+            power: 1000.0,
+            wavelength: C / 1.0e9,
+            azimuth_beam_width: 360.0,
+            elevation_beam_width: 90.0
+        })
+        .with(Receiver::default())
+        .build();
+        let other = world.create_entity()
+        .with(Position{x: -5000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .build();
+        let target = world.create_entity()
+        .with(Position{x: 1000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .build();
+
+        // Waves arriving from the target's position, pointed back at the radar
+        let wave = |world: &mut World, source: Option<WaveSource>| {
+            let builder = world.create_entity()
+            .with(Position{x: 1000.0, y: 0.0, z: 10.0, direction: 180.0})
+            .with(EMWave{
+                power: 1.0,
+                wavelength: C / 1.0e9,
+                frequency: 1.0e9,
+                doppler: 0.0,
+                azimuth_width: 20.0,
+                elevation_width: 20.0
+            });
+            match source {
+                Some(source) => builder.with(source).build(),
+                None => builder.build(),
+            };
+        };
+        wave(&mut world, Some(WaveSource{emitter: radar, waveform_id: 0, reflector: Some(target), bounces: 1}));
+        wave(&mut world, Some(WaveSource{emitter: radar, waveform_id: 0, reflector: None, bounces: 0}));
+        wave(&mut world, Some(WaveSource{emitter: other, waveform_id: 0, reflector: None, bounces: 0}));
+        wave(&mut world, Some(WaveSource{emitter: other, waveform_id: 0, reflector: Some(target), bounces: 1}));
+        wave(&mut world, None);
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+    #[test]
+    fn test_mutual_interference() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, Receiver>;
+
+            fn run(&mut self, receivers: Self::SystemData) {
+                for receiver in receivers.join() {
+                    // The co-channel radar gets through, the one 100 MHz away does not
+                    assert_eq!(receiver.interference.len(), 1);
+                    let interference = &receiver.interference[0];
+                    // Noise rise is the peak power times the 900 Hz x 1 us duty cycle
+                    assert!((receiver.interference_power - interference.power * 9e-4).abs() < interference.power * 1e-6);
+                    // Its slightly longer PRI walks the false targets out through the range bins
+                    assert!(receiver.interference_targets.len() > 1);
+                    assert!(receiver.interference_targets.iter().all(|t| t.power < interference.power && t.range < C * 1e-3 / 2.0));
+                    // The noise rise degrades the radar's own echo like jamming would, and the empty echo is dropped
+                    assert_eq!(receiver.signals.len(), 1);
+                    let echo = &receiver.signals[0];
+                    assert!(echo.jamming_to_signal > 0.0);
+                    assert!((echo.jamming_to_signal - receiver.interference_power / echo.power).abs() <= 1e-6 * echo.jamming_to_signal);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<Antenna>();
+
+        let mut sys = AntennaReceiverSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let radar = |frequency: f32| Antenna{
+            frequency,
+            gain: 100.0,
+            power: 1000.0,
+            wavelength: C / frequency,
+            azimuth_beam_width: 360.0,
+            elevation_beam_width: 90.0
+        };
+        let waveform = |prf: f32| Waveform{id: 0, prf, pulse_width: 1e-6, pulses: 10, stagger: Vec::new()};
+        let _victim = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(radar(1.0e9))
+        .with(waveform(1000.0))
+        .with(Receiver::default())
+        .build();
+        let co_channel = world.create_entity()
+        .with(Position{x: 1000.0, y: 0.0, z: 10.0, direction: 180.0})
+        .with(radar(1.0e9))
+        .with(waveform(900.0))
+        .build();
+        let off_channel = world.create_entity()
+        .with(Position{x: 0.0, y: 1000.0, z: 10.0, direction: 270.0})
+        .with(radar(1.1e9))
+        .with(waveform(1000.0))
+        .build();
+
+        // Each neighbour's main beam is pointed at the victim
+        let direct = |world: &mut World, emitter: Entity, x: f32, y: f32, direction: f32, frequency: f32| {
+            world.create_entity()
+            .with(Position{x, y, z: 10.0, direction})
+            .with(EMWave{
+                power: 1.0e5,
+                wavelength: C / frequency,
+                frequency,
+                doppler: 0.0,
+                azimuth_width: 20.0,
+                elevation_width: 20.0
+            }).with(WaveSource{emitter, waveform_id: 0, reflector: None, bounces: 0})
+            .build();
+        };
+        direct(&mut world, co_channel, 1000.0, 0.0, 180.0, 1.0e9);
+        direct(&mut world, off_channel, 0.0, 1000.0, 270.0, 1.1e9);
+        // Echoes of the victim's own pulse, one off a target with no cross section
+        for (x, power) in [(-2000.0, 1.0), (-3000.0, 0.0)].iter() {
+            world.create_entity()
+            .with(Position{x: *x, y: 0.0, z: 10.0, direction: 0.0})
+            .with(EMWave{
+                power: *power,
+                wavelength: C / 1.0e9,
+                frequency: 1.0e9,
+                doppler: 0.0,
+                azimuth_width: 20.0,
+                elevation_width: 20.0
+            }).build();
+        }
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+    #[test]
+    fn test_doppler_resolution() {
+        // A 300 Hz echo at X-band, followed through the whole pipeline
+        let mut simulation = crate::simulation::Simulation::new();
+        let waveform = Waveform{id: 0, prf: 1000.0, pulse_width: 1e-6, pulses: 16, stagger: Vec::new()};
+        let processor = SignalProcessor{mti: MtiFilter::TwoPulse, doppler_filters: 0, clutter_spread: 1.0};
+        let expected_gain = processing_gain(&processor, &waveform, 300.0);
+        let radar = simulation.world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(Antenna{
+            frequency: 9.4e9,
+            gain: 1000.0,
+            power: 1.0e5,
+            wavelength: C / 9.4e9,
+            azimuth_beam_width: 10.0,
+            elevation_beam_width: 10.0
+        })
+        .with(Scan::Fixed)
+        .with(waveform)
+        .with(processor)
+        .with(Receiver::default())
+        .build();
+        simulation.world.create_entity()
+        .with(Position{x: 5000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(Velocity{x: 300.0 * C / (2.0 * 9.4e9), y: 0.0, z: 0.0})
+        .with(isotropic_rcs(10.0))
+        .with(TargetIllumination{illuminations: Vec::new()})
+        .build();
+
+        simulation.step();
+        let receivers = simulation.world.read_storage::<Receiver>();
+        let receiver = receivers.get(radar).unwrap();
+        assert_eq!(receiver.signals.len(), 1);
+        let signal = &receiver.signals[0];
+        assert!((signal.doppler - 300.0).abs() < 0.1, "doppler was {}", signal.doppler);
+        // The MTI passes the mover instead of cancelling it as stationary
+        assert!(expected_gain > 0.1);
+        assert!((signal.processing_gain - expected_gain).abs() < 1e-3);
+    }
+}
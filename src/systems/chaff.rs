@@ -0,0 +1,127 @@
+use super::*;
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+// Ages chaff clouds, sets their cross section and Doppler spread on each illumination,
+// and lets them drift with the wind until they land or disperse
+pub struct ChaffSystem;
+impl<'a> System<'a> for ChaffSystem {
+    type SystemData = (
+        WriteStorage<'a, Chaff>,
+        WriteStorage<'a, TargetIllumination>,
+        WriteStorage<'a, RCS>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Position>,
+        Read<'a, Weather>,
+        Read<'a, SimulationTime>,
+        Write<'a, SimulationRng>,
+        Entities<'a>,
+    );
+
+    fn run(&mut self, (mut chaff, mut illuminations, mut cross_sections, mut velocities, positions, weather, time, mut rng, entities) : Self::SystemData) {
+        for (entity, cloud, targ, pos) in (&*entities, &mut chaff, &mut illuminations, &positions).join() {
+            let rcs = chaff_rcs(cloud);
+            for ill in targ.illuminations.iter_mut() {
+                ill.rcs = rcs;
+                ill.doppler += cloud.doppler_spread * rng.rng.sample::<f32, _>(StandardNormal);
+            }
+            if let Some(table) = cross_sections.get_mut(entity) {
+                *table = isotropic_rcs(rcs);
+            }
+            if let Some(vel) = velocities.get_mut(entity) {
+                *vel = Velocity{x: weather.wind_x, y: weather.wind_y, z: -cloud.fall_rate};
+            }
+
+            cloud.age += time.delta;
+            if cloud.age > cloud.lifetime || pos.z < 0.0 {
+                if let Err(e) = entities.delete(entity) {
+                    eprintln!("Error!\n {}", e);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chaff() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, Chaff>,
+                ReadStorage<'a, TargetIllumination>,
+                ReadStorage<'a, Velocity>
+            );
+
+            fn run(&mut self, (chaff, illuminations, velocities): Self::SystemData) {
+                // The expired cloud was removed
+                assert_eq!(chaff.count(), 1);
+                for (cloud, targ, vel) in (&chaff, &illuminations, &velocities).join() {
+                    assert_eq!(cloud.age, 11.0);
+                    assert_eq!(vel, &Velocity{x: 5.0, y: -2.0, z: -1.5});
+                    let ill = &targ.illuminations[0];
+                    assert!(ill.rcs > 90.0 && ill.rcs < 100.0);
+                    // A spread of tens of Hz survives on the Doppler offset at X-band
+                    assert_eq!(ill.frequency, 9.4e9);
+                    assert!(ill.doppler != 0.0 && ill.doppler.abs() < 200.0);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<RCS>();
+        world.insert(Weather{wind_x: 5.0, wind_y: -2.0, ..Weather::default()});
+
+        let mut sys = ChaffSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let cloud = |age: f32| Chaff{
+            max_rcs: 100.0,
+            bloom_time: 1.0,
+            decay_time: 1000.0,
+            fall_rate: 1.5,
+            doppler_spread: 20.0,
+            lifetime: 60.0,
+            age
+        };
+        let illuminated = || TargetIllumination{
+            illuminations: vec![Illumination{
+                angle: 0.0,
+                elevation: 0.0,
+                frequency: 9.4e9,
+                doppler: 0.0,
+                lambda: C / 9.4e9,
+                rcs: 0.0,
+                power: 1.0,
+                source: None
+            }]
+        };
+
+        for age in [10.0, 60.0].iter() {
+            world.create_entity()
+            .with(Position{x: 0.0, y: 0.0, z: 1000.0, direction: 0.0})
+            .with(Velocity{x: 0.0, y: 0.0, z: 0.0})
+            .with(isotropic_rcs(0.0))
+            .with(cloud(*age))
+            .with(illuminated())
+            .build();
+        }
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+// Advances the simulation clock by one time step. Runs once at the end of each tick.
+pub struct ClockSystem;
+impl<'a> System<'a> for ClockSystem {
+    type SystemData = Write<'a, SimulationTime>;
+
+    fn run(&mut self, mut time : Self::SystemData) {
+        time.elapsed += time.delta;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock() {
+        let mut world = World::new();
+        world.insert(SimulationTime{delta: 0.5, elapsed: 0.0});
+
+        let mut sys = ClockSystem;
+        System::setup(&mut sys, &mut world);
+        sys.run_now(&world);
+        sys.run_now(&world);
+
+        assert_eq!(world.read_resource::<SimulationTime>().elapsed, 1.0);
+    }
+}
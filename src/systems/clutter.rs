@@ -0,0 +1,142 @@
+use super::*;
+
+// Generates clutter echoes for each range cell along every radar beam.
+// Echoes are ordinary EMWave entities, so they reach the receiver alongside target echoes.
+pub struct ClutterSystem;
+impl<'a> System<'a> for ClutterSystem {
+    type SystemData = (
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, EMWave>,
+        ReadStorage<'a, WaveSource>,
+        Read<'a, Clutter>,
+        Read<'a, Surface>,
+        Read<'a, Weather>,
+        Read<'a, EarthModel>,
+        ReadStorage<'a, RainCell>,
+        Write<'a, SimulationRng>,
+        Entities<'a>,
+        Read<'a, LazyUpdate>,
+    );
+
+    fn run(&mut self, (positions, emissions, sources, clutter, surface, weather, earth, rain_cells, mut rng, entities, updater): Self::SystemData) {
+        if clutter.model == ClutterModel::None || clutter.range_resolution <= 0.0 {
+            return;
+        }
+        for (em, em_pos, source) in (&emissions, &positions, sources.maybe()).join() {
+            let height = em_pos.z;
+            let horizon = horizon_distance(height, &earth);
+            let (sin_dir, cos_dir) = em_pos.direction.to_radians().sin_cos();
+            let mut range = clutter.range_resolution;
+            while range <= clutter.max_range {
+                let ground_range = (range.powi(2) - height.powi(2)).max(0.0).sqrt();
+                if range > height && ground_range <= horizon {
+                    let grazing = (height / range).asin().to_degrees();
+                    let sigma0 = match clutter.model {
+                        ClutterModel::ConstantGamma{terrain} => constant_gamma_sigma0(terrain, grazing),
+                        ClutterModel::GitSea{sea_state, wind_direction} => {
+                            git_sea_sigma0(sea_state, grazing, em_pos.direction - wind_direction, em.wavelength, surface.polarization)
+                        },
+                        ClutterModel::None => 0.0,
+                    };
+                    // Cell is one beam width across and one range resolution deep
+                    let area = range * em.azimuth_width.to_radians() * clutter.range_resolution / grazing.to_radians().cos();
+                    let sigma = sigma0 * area * clutter_fluctuation(clutter.statistics, &mut rng.rng);
+                    let cell_pos = Position{
+                        x: em_pos.x + ground_range * cos_dir,
+                        y: em_pos.y + ground_range * sin_dir,
+                        z: 0.0,
+                        direction: (em_pos.direction + 180.0) % 360.0
+                    };
+                    let density = em.power * atmospheric_loss(em.frequency, range, &weather)
+                        * rain_cell_loss(em_pos, &cell_pos, em.frequency, (&rain_cells, &positions).join())
+                        / (4.0 * std::f32::consts::PI * range.powi(2));
+
+                    let new_entity = entities.create();
+                    updater.insert(new_entity, cell_pos);
+                    updater.insert(new_entity, EMWave{
+                        power: density * sigma,
+                        wavelength: em.wavelength,
+                        frequency: em.frequency,
+                        doppler: em.doppler,
+                        azimuth_width: 20.0,
+                        elevation_width: 20.0
+                    });
+                    if let Some(source) = source {
+                        updater.insert(new_entity, WaveSource{reflector: None, bounces: source.bounces + 1, ..*source});
+                    }
+                }
+                range += clutter.range_resolution;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clutter() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, EMWave>,
+                ReadStorage<'a, Position>
+            );
+
+            fn run(&mut self, (em_waves, positions): Self::SystemData) {
+                // The radar's own wave plus five clutter cells
+                assert_eq!(em_waves.count(), 6);
+                for (em_wave, pos) in (&em_waves, &positions).join() {
+                    if pos.z == 0.0 {
+                        assert_eq!(pos.direction, 180.0);
+                        assert!(em_wave.power > 0.0);
+                        assert!(em_wave.power < 1000.0);
+                    }
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+
+        world.insert(Clutter{
+            model: ClutterModel::ConstantGamma{terrain: TerrainType::Farmland},
+            statistics: ClutterStatistics::Mean,
+            range_resolution: 1000.0,
+            max_range: 5000.0
+        });
+
+        let mut sys = ClutterSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create EMWave Entity
+        let _em_wave = world.create_entity()
+        .with(EMWave{
+            frequency: 3e9,
+            doppler: 0.0,
+            power: 1000.0,
+            wavelength: 0.1,
+            azimuth_width: 2.0,
+            elevation_width: 10.0
+        }).with(Position{
+            x: 0.0,
+            y: 0.0,
+            z: 100.0,
+            direction: 0.0
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
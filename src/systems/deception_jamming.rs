@@ -0,0 +1,164 @@
+use super::*;
+
+// DRFM repeaters answer each illumination with false echoes offset in range and Doppler
+pub struct DeceptionJammingSystem;
+impl<'a> System<'a> for DeceptionJammingSystem {
+    type SystemData = (
+        ReadStorage<'a, TargetIllumination>,
+        WriteStorage<'a, DeceptionJammer>,
+        WriteStorage<'a, EMWave>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, FalseTarget>,
+        WriteStorage<'a, WaveSource>,
+        Read<'a, SimulationTime>,
+        Entities<'a>,
+    );
+
+    fn run(&mut self, (target_illumination, mut jammers, mut emission, mut position, mut false_targets, mut sources, time, entities) : Self::SystemData) {
+
+        let mut new_positions: Vec<Position> = Vec::new();
+        let mut new_emissions: Vec<EMWave> = Vec::new();
+        let mut new_false: Vec<FalseTarget> = Vec::new();
+        let mut new_sources: Vec<Option<WaveSource>> = Vec::new();
+        for (repeater, target, jammer, pos) in (&*entities, &target_illumination, &mut jammers, &position).join() {
+            // The pull-off only advances while a radar keeps the jammer illuminated
+            if target.illuminations.is_empty() {
+                jammer.engaged_time = 0.0;
+                continue;
+            }
+            jammer.engaged_time += time.delta;
+            if jammer.engaged_time > pull_off_cycle(jammer) {
+                jammer.engaged_time = time.delta;
+            }
+            let (range_offset, doppler_offset) = pull_off_offsets(jammer, jammer.engaged_time);
+
+            for ill in target.illuminations.iter() {
+                // The pull-off echo repeats the pulse just received, the false target train is played out ahead of time
+                let echoes = std::iter::once((range_offset, doppler_offset, false))
+                    .chain(jammer.false_targets.iter().map(|offset| (*offset, 0.0, true)));
+                for (range, doppler, predicted) in echoes {
+                    new_positions.push(offset_from_radar(pos, ill.angle, range));
+                    // The velocity gate walk rides on top of the skin return's Doppler, the carrier is repeated as is
+                    new_emissions.push(EMWave{
+                        power: ill.power * jammer.effective_rcs,
+                        wavelength: ill.lambda,
+                        frequency: ill.frequency,
+                        doppler: ill.doppler + doppler,
+                        azimuth_width: 20.0,
+                        elevation_width: 20.0
+                    });
+                    new_false.push(FalseTarget{range_offset: range, predicted: predicted || range < 0.0});
+                    // A copy of the radar's own waveform, so the radar takes it for its echo
+                    new_sources.push(ill.source.map(|source| WaveSource{reflector: Some(repeater), bounces: source.bounces + 1, ..source}));
+                }
+            }
+        }
+
+        while !new_positions.is_empty() {
+            let new_entity = entities.create();
+            if let Err(e) = position.insert(new_entity, new_positions.remove(0)) {
+                println!("{:?}", e);
+            }
+            if let Err(e) = emission.insert(new_entity, new_emissions.remove(0)) {
+                println!("{:?}", e);
+            }
+            if let Err(e) = false_targets.insert(new_entity, new_false.remove(0)) {
+                println!("{:?}", e);
+            }
+            if let Some(source) = new_sources.remove(0) {
+                if let Err(e) = sources.insert(new_entity, source) {
+                    println!("{:?}", e);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deception_jamming() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, EMWave>,
+                ReadStorage<'a, Position>,
+                ReadStorage<'a, DeceptionJammer>,
+                ReadStorage<'a, FalseTarget>
+            );
+
+            fn run(&mut self, (em_waves, positions, jammers, false_targets): Self::SystemData) {
+                // One pull-off echo and two false targets
+                assert_eq!(em_waves.count(), 3);
+                assert_eq!(false_targets.count(), 3);
+                for jammer in jammers.join() {
+                    assert_eq!(jammer.engaged_time, 4.0);
+                }
+                let mut echoes: Vec<(f32, f32)> = (&em_waves, &positions).join()
+                    .map(|(em, pos)| (pos.x, em.doppler))
+                    .collect();
+                echoes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                // Walked 200 m and 100 Hz away from the skin return at x = 1000 and its 40 Hz Doppler
+                assert_eq!(echoes[0], (1200.0, 140.0));
+                assert_eq!(echoes[1], (1500.0, 40.0));
+                assert_eq!(echoes[2], (3000.0, 40.0));
+                for (em_wave, pos) in (&em_waves, &positions).join() {
+                    assert_eq!(em_wave.power, 100.0);
+                    assert_eq!(em_wave.frequency, 9.4e9);
+                    assert_eq!(pos.direction, 180.0);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.insert(SimulationTime{delta: 2.0, elapsed: 0.0});
+
+        let mut sys = DeceptionJammingSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create jammer illuminated by a radar at the origin
+        let _jammer = world.create_entity()
+        .with(Position{
+            x: 1000.0,
+            y: 0.0,
+            z: 0.0,
+            direction: 0.0
+        }).with(DeceptionJammer{
+            effective_rcs: 1000.0,
+            hold_time: 2.0,
+            range_walk_rate: 100.0,
+            max_range_offset: 1000.0,
+            doppler_walk_rate: 50.0,
+            max_doppler_offset: 500.0,
+            false_targets: vec![500.0, 2000.0],
+            engaged_time: 2.0
+        }).with(TargetIllumination{
+            illuminations: vec![Illumination{
+                angle: 180.0,
+                elevation: 0.0,
+                frequency: 9.4e9,
+                doppler: 40.0,
+                lambda: C / 9.4e9,
+                rcs: 1.0,
+                power: 0.1,
+                source: None
+            }]
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
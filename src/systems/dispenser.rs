@@ -0,0 +1,181 @@
+use super::*;
+
+// Launches chaff and decoys from dispensers whose trigger has fired
+pub struct DispenserSystem;
+impl<'a> System<'a> for DispenserSystem {
+    type SystemData = (
+        WriteStorage<'a, Dispenser>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Velocity>,
+        ReadStorage<'a, TargetIllumination>,
+        Read<'a, SimulationTime>,
+        Read<'a, Weather>,
+        Entities<'a>,
+        Read<'a, LazyUpdate>,
+    );
+
+    fn run(&mut self, (mut dispensers, positions, velocities, illuminations, time, weather, entities, updater) : Self::SystemData) {
+        for (entity, dispenser, pos, vel, targ) in (&*entities, &mut dispensers, &positions, velocities.maybe(), illuminations.maybe()).join() {
+            let triggered = match dispenser.trigger {
+                DispenseTrigger::Illuminated => targ.is_some_and(|t| !t.illuminations.is_empty()),
+                DispenseTrigger::AtTime(t) => time.elapsed >= t,
+            };
+            let ready = dispenser.last_dispensed.is_none_or(|last| time.elapsed - last >= dispenser.interval);
+            if dispenser.remaining == 0 || !triggered || !ready {
+                continue;
+            }
+            dispenser.remaining -= 1;
+            dispenser.last_dispensed = Some(time.elapsed);
+
+            let new_entity = entities.create();
+            updater.insert(new_entity, Position{x: pos.x, y: pos.y, z: pos.z, direction: pos.direction});
+            updater.insert(new_entity, TargetIllumination{illuminations: Vec::new()});
+            let (rcs, velocity, repeater_rcs) = match dispenser.expendable {
+                Expendable::Chaff(chaff) => {
+                    updater.insert(new_entity, Chaff{age: 0.0, ..chaff});
+                    (0.0, Velocity{x: weather.wind_x, y: weather.wind_y, z: -chaff.fall_rate}, None)
+                },
+                Expendable::TowedDecoy{rcs, cable_length, repeater_rcs} => {
+                    updater.insert(new_entity, TowedDecoy{tow: entity, cable_length});
+                    let velocity = vel.map_or(Velocity{x: 0.0, y: 0.0, z: 0.0}, |v| Velocity{x: v.x, y: v.y, z: v.z});
+                    (rcs, velocity, repeater_rcs)
+                },
+                Expendable::FreeDecoy{rcs, speed, repeater_rcs} => {
+                    // Flies on along the dispenser's track, or its heading when it is stationary
+                    let (sin_h, cos_h) = pos.direction.to_radians().sin_cos();
+                    let velocity = match vel {
+                        Some(v) if v.x != 0.0 || v.y != 0.0 || v.z != 0.0 => {
+                            let norm = (v.x.powi(2) + v.y.powi(2) + v.z.powi(2)).sqrt();
+                            Velocity{x: speed * v.x / norm, y: speed * v.y / norm, z: speed * v.z / norm}
+                        },
+                        _ => Velocity{x: speed * cos_h, y: speed * sin_h, z: 0.0},
+                    };
+                    (rcs, velocity, repeater_rcs)
+                },
+            };
+            updater.insert(new_entity, isotropic_rcs(rcs));
+            updater.insert(new_entity, velocity);
+            if let Some(effective_rcs) = repeater_rcs {
+                // A repeater decoy answers on the skin return, without walking off
+                updater.insert(new_entity, DeceptionJammer{
+                    effective_rcs,
+                    hold_time: 0.0,
+                    range_walk_rate: 0.0,
+                    max_range_offset: 0.0,
+                    doppler_walk_rate: 0.0,
+                    max_doppler_offset: 0.0,
+                    false_targets: Vec::new(),
+                    engaged_time: 0.0
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispenser() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, Dispenser>,
+                ReadStorage<'a, Chaff>,
+                ReadStorage<'a, TowedDecoy>,
+                ReadStorage<'a, DeceptionJammer>,
+                ReadStorage<'a, Velocity>
+            );
+
+            fn run(&mut self, (dispensers, chaff, towed, repeaters, velocities): Self::SystemData) {
+                // The illuminated dispenser launched chaff, the scripted one a repeater decoy, the early one nothing
+                assert_eq!(chaff.count(), 1);
+                assert_eq!(towed.count(), 1);
+                assert_eq!(repeaters.count(), 1);
+                for (_decoy, vel) in (&towed, &velocities).join() {
+                    assert_eq!(vel, &Velocity{x: 250.0, y: 0.0, z: 0.0});
+                }
+                let remaining: Vec<u32> = dispensers.join().map(|d| d.remaining).collect();
+                assert_eq!(remaining.iter().sum::<u32>(), 5 + 1 + 2);
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<RCS>();
+        world.register::<TargetIllumination>();
+        world.register::<Chaff>();
+        world.register::<TowedDecoy>();
+        world.register::<DeceptionJammer>();
+        world.insert(SimulationTime{delta: 1.0, elapsed: 30.0});
+
+        let mut sys = DispenserSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let position = || Position{x: 0.0, y: 0.0, z: 1000.0, direction: 0.0};
+
+        let _illuminated = world.create_entity()
+        .with(position())
+        .with(TargetIllumination{
+            illuminations: vec![Illumination{
+                angle: 0.0,
+                elevation: 0.0,
+                frequency: 1.0e9,
+                doppler: 0.0,
+                lambda: C / 1.0e9,
+                rcs: 1.0,
+                power: 1.0,
+                source: None
+            }]
+        }).with(Dispenser{
+            trigger: DispenseTrigger::Illuminated,
+            expendable: Expendable::Chaff(Chaff{
+                max_rcs: 50.0,
+                bloom_time: 0.5,
+                decay_time: 30.0,
+                fall_rate: 2.0,
+                doppler_spread: 10.0,
+                lifetime: 60.0,
+                age: 0.0
+            }),
+            remaining: 6,
+            interval: 1.0,
+            last_dispensed: Some(28.0)
+        }).build();
+
+        let _scripted = world.create_entity()
+        .with(position())
+        .with(Velocity{x: 250.0, y: 0.0, z: 0.0})
+        .with(Dispenser{
+            trigger: DispenseTrigger::AtTime(20.0),
+            expendable: Expendable::TowedDecoy{rcs: 10.0, cable_length: 100.0, repeater_rcs: Some(500.0)},
+            remaining: 2,
+            interval: 0.0,
+            last_dispensed: None
+        }).build();
+
+        let _early = world.create_entity()
+        .with(position())
+        .with(Dispenser{
+            trigger: DispenseTrigger::AtTime(40.0),
+            expendable: Expendable::FreeDecoy{rcs: 10.0, speed: 200.0, repeater_rcs: None},
+            remaining: 2,
+            interval: 0.0,
+            last_dispensed: None
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -10,7 +10,7 @@ impl<'a> System<'a> for DopplerShiftSystem {
     fn run(&mut self, (velocities, mut target_ills) : Self::SystemData) {
         for (vel, targ) in (&velocities, &mut target_ills).join() {
             for ill in targ.illuminations.iter_mut() {
-                ill.frequency = doppler_shift(vel, ill);
+                ill.doppler += doppler_offset(vel, ill);
             }
         }
     }
@@ -31,13 +31,10 @@ mod tests {
                     // Make sure the system made the correct number of illuminations
                     assert_eq!(illums.illuminations.len(), 1); 
                     for illum in illums.illuminations.iter() {
-                        assert_eq!(illum, &Illumination{
-                            angle: 90.0,
-                            frequency: 10.00001,
-                            lambda: 100.0,
-                            rcs: 1.0,
-                            power: 10.0
-                        });
+                        // The carrier is left alone and the shift carried beside it
+                        assert_eq!(illum.frequency, 10.0);
+                        assert!((illum.doppler - 9.4346e-6).abs() < 1e-9);
+                        assert_eq!(illum.power, 10.0);
                     }
                 }
             }
@@ -67,10 +64,13 @@ mod tests {
         }).with(TargetIllumination{
             illuminations: vec![Illumination{
                 angle: 90.0,
+                elevation: 0.0,
                 frequency: 10.0,
+                doppler: 0.0,
                 lambda: 100.0,
                 rcs: 1.0,
-                power: 10.0
+                power: 10.0,
+                source: None
             }]
         }).build();
 
@@ -0,0 +1,79 @@
+use super::*;
+use rand::Rng;
+
+// Retunes frequency agile radars at the start of each dwell
+pub struct EccmSystem;
+impl<'a> System<'a> for EccmSystem {
+    type SystemData = (
+        ReadStorage<'a, Eccm>,
+        WriteStorage<'a, Antenna>,
+        Write<'a, SimulationRng>,
+    );
+
+    fn run(&mut self, (eccm, mut antennas, mut rng) : Self::SystemData) {
+        for (eccm, antenna) in (&eccm, &mut antennas).join() {
+            if let FrequencyAgility::PerDwell{center, bandwidth} = eccm.agility {
+                let offset = if bandwidth > 0.0 { rng.rng.gen_range(-0.5..0.5) * bandwidth } else { 0.0 };
+                antenna.frequency = center + offset;
+                antenna.wavelength = C / antenna.frequency;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frequency_agility() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, Antenna>;
+
+            fn run(&mut self, antennas: Self::SystemData) {
+                for antenna in antennas.join() {
+                    assert!(antenna.frequency >= 8.95e9 && antenna.frequency <= 9.05e9);
+                    assert_eq!(antenna.wavelength, C / antenna.frequency);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Antenna>();
+
+        let mut sys = EccmSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let _radar = world.create_entity()
+        .with(Antenna{
+            frequency: 9.0e9,
+            gain: 1000.0,
+            power: 1000.0,
+            wavelength: C / 9.0e9,
+            azimuth_beam_width: 3.0,
+            elevation_beam_width: 3.0
+        }).with(Eccm{
+            agility: FrequencyAgility::PerDwell{center: 9.0e9, bandwidth: 100e6},
+            sidelobe_level: 0.001,
+            sidelobe_blanking: false,
+            guard_level: 0.0,
+            cancellation_channels: 0,
+            cancellation_ratio: 1.0,
+            prf_jitter: false,
+            home_on_jam: false
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -0,0 +1,147 @@
+use super::*;
+use rand::Rng;
+
+// Turns each passive receiver's intercepts into PDWs, deinterleaves them and keeps emitter tracks
+pub struct ElintSystem;
+impl<'a> System<'a> for ElintSystem {
+    type SystemData = (
+        WriteStorage<'a, Elint>,
+        ReadStorage<'a, EsmReceiver>,
+        Read<'a, EmitterLibrary>,
+        Read<'a, SimulationTime>,
+        Write<'a, SimulationRng>,
+    );
+
+    fn run(&mut self, (mut elint, esm, library, time, mut rng) : Self::SystemData) {
+        for (elint, esm) in (&mut elint, &esm).join() {
+            let mut pdws = Vec::new();
+            for intercept in esm.intercepts.iter() {
+                pdws.extend(generate_pdws(intercept, elint.dwell, rng.rng.gen::<f32>()));
+            }
+
+            let (tolerance, gate) = (elint.pri_tolerance, elint.angle_gate);
+            for (pri, train) in deinterleave(&pdws, elint.pri_tolerance, elint.min_pulses) {
+                let count = train.len() as f32;
+                let angles: Vec<f32> = train.iter().map(|pdw| pdw.angle).collect();
+                let angle = circular_mean(&angles);
+                let frequency = train.iter().map(|pdw| pdw.frequency).sum::<f32>() / count;
+                let pulse_width = train.iter().map(|pdw| pdw.pulse_width).sum::<f32>() / count;
+                let name = identify_emitter(&library, frequency, pri, pulse_width).map(|e| e.name.clone());
+
+                let existing = elint.tracks.iter_mut().find(|track| {
+                    track.name == name && (track.pri - pri).abs() <= tolerance
+                        && ((track.angle - angle + 540.0) % 360.0 - 180.0).abs() <= gate
+                });
+                match existing {
+                    Some(track) => {
+                        track.angle = angle;
+                        track.frequency = frequency;
+                        track.pri = pri;
+                        track.pulse_width = pulse_width;
+                        track.updates += 1;
+                        track.last_seen = time.elapsed;
+                    },
+                    None => elint.tracks.push(EmitterTrack{
+                        name,
+                        angle,
+                        frequency,
+                        pri,
+                        pulse_width,
+                        updates: 1,
+                        last_seen: time.elapsed
+                    }),
+                }
+            }
+
+            let timeout = elint.track_timeout;
+            elint.tracks.retain(|track| time.elapsed - track.last_seen <= timeout);
+            elint.pdws = pdws;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elint() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, Elint>;
+
+            fn run(&mut self, elint: Self::SystemData) {
+                for elint in elint.join() {
+                    assert_eq!(elint.pdws.len(), 20 + 10);
+                    // The stale track timed out, the known one was updated and the unknown emitter added
+                    assert_eq!(elint.tracks.len(), 2);
+                    let known = elint.tracks.iter().find(|t| t.name.is_some()).unwrap();
+                    assert_eq!(known.name.as_deref(), Some("X-band surveillance"));
+                    assert_eq!(known.updates, 2);
+                    assert_eq!(known.last_seen, 5.0);
+                    let unknown = elint.tracks.iter().find(|t| t.name.is_none()).unwrap();
+                    assert!((unknown.pri - 0.002).abs() < 1e-6);
+                    assert!((unknown.angle - 270.0).abs() < 1e-3);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EsmReceiver>();
+        world.insert(SimulationTime{delta: 1.0, elapsed: 5.0});
+        world.insert(load_emitter_library("src/emitters.json").unwrap());
+
+        let mut sys = ElintSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let intercept = |angle: f32, frequency: f32, pri: f32| Intercept{
+            angle,
+            frequency,
+            doppler: 0.0,
+            pri: Some(pri),
+            pulse_width: Some(1e-6),
+            power: 1e-9,
+            time: 1e-5,
+            time_of_arrival: 1e-5
+        };
+        let track = |name: Option<&str>, pri: f32, last_seen: f32| EmitterTrack{
+            name: name.map(String::from),
+            angle: 45.0,
+            frequency: 9.4e9,
+            pri,
+            pulse_width: 1e-6,
+            updates: 1,
+            last_seen
+        };
+
+        let _esm = world.create_entity()
+        .with(EsmReceiver{
+            gain: 1.0,
+            sensitivity: 1e-12,
+            min_frequency: 1.0e9,
+            max_frequency: 18.0e9,
+            df_accuracy: 0.0,
+            intercepts: vec![intercept(45.0, 9.4e9, 0.001), intercept(270.0, 5.0e9, 0.002)]
+        }).with(Elint{
+            dwell: 0.02,
+            pri_tolerance: 2e-6,
+            min_pulses: 5,
+            angle_gate: 5.0,
+            track_timeout: 10.0,
+            pdws: Vec::new(),
+            tracks: vec![track(Some("X-band surveillance"), 0.001, 4.0), track(None, 0.003, -10.0)]
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -0,0 +1,237 @@
+use super::*;
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+// Passive receivers intercept every emission that reaches them above their sensitivity and inside their band.
+// Runs alongside AntennaReceiverSystem, before the received waves are removed.
+pub struct EsmSystem;
+impl<'a> System<'a> for EsmSystem {
+    type SystemData = (
+        WriteStorage<'a, EsmReceiver>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Velocity>,
+        ReadStorage<'a, EMWave>,
+        ReadStorage<'a, Waveform>,
+        Read<'a, EarthModel>,
+        Read<'a, Terrain>,
+        Read<'a, Weather>,
+        Read<'a, Surface>,
+        ReadStorage<'a, RainCell>,
+        Read<'a, Geolocation>,
+        Read<'a, SimulationTime>,
+        Write<'a, SimulationRng>,
+    );
+
+    fn run(&mut self, (mut esm, positions, velocities, emissions, waveforms, earth, terrain, weather, surface, rain_cells, geolocation, time, mut rng) : Self::SystemData) {
+        for (esm, esm_pos, esm_vel) in (&mut esm, &positions, velocities.maybe()).join() {
+            esm.intercepts.clear();
+            for (em, em_pos, waveform) in (&emissions, &positions, waveforms.maybe()).join() {
+                let angle = incident_angle(em_pos, esm_pos);
+                if em.frequency < esm.min_frequency || em.frequency > esm.max_frequency
+                    || !check_illumination(em.azimuth_width, em_pos.direction, angle)
+                    || !line_of_sight(em_pos, esm_pos, &earth) || !terrain_clear(&terrain, em_pos, esm_pos) {
+                    continue;
+                }
+                let range = slant_range(em_pos, esm_pos);
+                let density = em.power * atmospheric_loss(em.frequency, range, &weather)
+                    * rain_cell_loss(em_pos, esm_pos, em.frequency, (&rain_cells, &positions).join())
+                    * propagation_factor(em_pos, esm_pos, em.wavelength, &surface).powi(2)
+                    / (4.0 * std::f32::consts::PI * range.powi(2));
+                let power = density * esm.gain * em.wavelength.powi(2) / (4.0 * std::f32::consts::PI);
+                if power < esm.sensitivity {
+                    continue;
+                }
+
+                // Moving away from the emitter lowers the frequency heard
+                let radial_velocity = esm_vel.map_or(0.0, |vel| {
+                    (vel.x * (esm_pos.x - em_pos.x) + vel.y * (esm_pos.y - em_pos.y) + vel.z * (esm_pos.z - em_pos.z)) / range
+                });
+                // Every measurement carries its own error, arrival time and Doppler to the accuracies geolocation assumes
+                let mut noise = || rng.rng.sample::<f32, _>(StandardNormal);
+                let bearing = incident_angle(esm_pos, em_pos) + esm.df_accuracy * noise();
+                let doppler_error = geolocation.frequency_accuracy * noise();
+                let time_error = geolocation.time_accuracy * noise();
+                esm.intercepts.push(Intercept{
+                    angle: bearing.rem_euclid(360.0),
+                    frequency: one_way_doppler(em.frequency, radial_velocity) + doppler_error,
+                    doppler: -em.frequency * radial_velocity / C + doppler_error,
+                    pri: waveform.map(|wf| 1.0 / wf.prf),
+                    pulse_width: waveform.map(|wf| wf.pulse_width),
+                    power,
+                    time: time.elapsed + range / C + time_error,
+                    time_of_arrival: range / C + time_error
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_esm() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, EsmReceiver>;
+
+            fn run(&mut self, receivers: Self::SystemData) {
+                for esm in receivers.join() {
+                    // The out of band and the too weak emissions are missed
+                    assert_eq!(esm.intercepts.len(), 1);
+                    let intercept = &esm.intercepts[0];
+                    assert_eq!(intercept.angle, 180.0);
+                    assert_eq!(intercept.pri, Some(0.001));
+                    assert_eq!(intercept.pulse_width, Some(1e-6));
+                    // Flying away from the radar at 300 m/s
+                    assert_eq!(intercept.frequency, 1.0e9 - 1000.0);
+                    assert_eq!(intercept.doppler, -1.0e9 * 300.0 / C);
+                    assert_eq!(intercept.time, 10.0 + 3000.0 / C);
+                    assert_eq!(intercept.time_of_arrival, 3000.0 / C);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<Waveform>();
+        world.insert(Geolocation{time_accuracy: 0.0, frequency_accuracy: 0.0, ..Geolocation::default()});
+        world.insert(SimulationTime{delta: 1.0, elapsed: 10.0});
+
+        let mut sys = EsmSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let _rwr = world.create_entity()
+        .with(Position{x: 3000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(Velocity{x: 300.0, y: 0.0, z: 0.0})
+        .with(EsmReceiver{
+            gain: 1.0,
+            sensitivity: 1e-12,
+            min_frequency: 0.5e9,
+            max_frequency: 18.0e9,
+            df_accuracy: 0.0,
+            intercepts: Vec::new()
+        }).build();
+
+        let emission = |power: f32, frequency: f32| EMWave{
+            power,
+            wavelength: C / frequency,
+            frequency,
+            doppler: 0.0,
+            azimuth_width: 10.0,
+            elevation_width: 10.0
+        };
+        let radar_pos = || Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0};
+        world.create_entity().with(radar_pos()).with(emission(1.0e6, 1.0e9)).with(Waveform{
+            id: 0,
+            prf: 1000.0,
+            pulse_width: 1e-6,
+            pulses: 16,
+            stagger: Vec::new()
+        }).build();
+        world.create_entity().with(radar_pos()).with(emission(1.0e6, 0.1e9)).build();
+        world.create_entity().with(radar_pos()).with(emission(1e-12, 1.0e9)).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+
+    #[test]
+    fn test_rwr_in_pipeline() {
+        // A radar warning receiver hears the radar pointed at it, not just echoes and jamming
+        let mut simulation = crate::simulation::Simulation::new();
+        simulation.world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(Antenna{
+            frequency: 9.4e9,
+            gain: 1000.0,
+            power: 1.0e5,
+            wavelength: C / 9.4e9,
+            azimuth_beam_width: 10.0,
+            elevation_beam_width: 10.0
+        })
+        .with(Scan::Fixed)
+        .with(Waveform{id: 0, prf: 1000.0, pulse_width: 1e-6, pulses: 16, stagger: Vec::new()})
+        .with(Receiver::default())
+        .build();
+        let rwr = simulation.world.create_entity()
+        .with(Position{x: 20_000.0, y: 0.0, z: 3000.0, direction: 180.0})
+        .with(EsmReceiver{
+            gain: 1.0,
+            sensitivity: 1e-10,
+            min_frequency: 2.0e9,
+            max_frequency: 18.0e9,
+            df_accuracy: 0.0,
+            intercepts: Vec::new()
+        }).build();
+
+        simulation.step();
+        let esm = simulation.world.read_storage::<EsmReceiver>();
+        let intercepts = &esm.get(rwr).unwrap().intercepts;
+        assert_eq!(intercepts.len(), 1);
+        assert_eq!(intercepts[0].angle, 180.0);
+        assert_eq!(intercepts[0].pri, Some(1e-3));
+        assert_eq!(intercepts[0].frequency, 9.4e9);
+    }
+
+    #[test]
+    fn test_measurement_noise() {
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.insert(Geolocation{time_accuracy: 1e-7, frequency_accuracy: 10.0, ..Geolocation::default()});
+
+        let mut sys = EsmSystem;
+        System::setup(&mut sys, &mut world);
+
+        let rwr = world.create_entity()
+        .with(Position{x: 3000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(EsmReceiver{
+            gain: 1.0,
+            sensitivity: 1e-12,
+            min_frequency: 0.5e9,
+            max_frequency: 18.0e9,
+            df_accuracy: 0.0,
+            intercepts: Vec::new()
+        }).build();
+        world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(EMWave{
+            power: 1.0e6,
+            wavelength: C / 1.0e9,
+            frequency: 1.0e9,
+            doppler: 0.0,
+            azimuth_width: 10.0,
+            elevation_width: 10.0
+        }).build();
+
+        // The spread of the errors follows the configured accuracies
+        let n = 2000;
+        let (mut time_errors, mut doppler_errors) = (0.0, 0.0);
+        for _ in 0..n {
+            sys.run_now(&world);
+            let esm = world.read_storage::<EsmReceiver>();
+            let intercept = &esm.get(rwr).unwrap().intercepts[0];
+            time_errors += (intercept.time_of_arrival - 3000.0 / C).powi(2);
+            doppler_errors += intercept.doppler.powi(2);
+        }
+        let time_sigma = (time_errors / n as f32).sqrt();
+        let doppler_sigma = (doppler_errors / n as f32).sqrt();
+        assert!((time_sigma / 1e-7 - 1.0).abs() < 0.1, "time sigma was {}", time_sigma);
+        assert!((doppler_sigma / 10.0 - 1.0).abs() < 0.1, "doppler sigma was {}", doppler_sigma);
+    }
+}
@@ -0,0 +1,83 @@
+use super::*;
+
+// Places entities given in latitude / longitude / altitude into the local frame,
+// then keeps their geodetic coordinates in step with their Position as they move
+pub struct GeodeticSystem;
+impl<'a> System<'a> for GeodeticSystem {
+    type SystemData = (
+        WriteStorage<'a, GeodeticPosition>,
+        WriteStorage<'a, Position>,
+        Read<'a, GeodeticOrigin>,
+        Entities<'a>,
+    );
+
+    fn run(&mut self, (mut geodetics, mut positions, origin, entities): Self::SystemData) {
+        let mut new_positions: Vec<(Entity, Position)> = Vec::new();
+        for (entity, geo, _) in (&*entities, &geodetics, !&positions).join() {
+            new_positions.push((entity, geodetic_to_position(geo, &origin.origin, 0.0)));
+        }
+        for (entity, pos) in new_positions {
+            if let Err(e) = positions.insert(entity, pos) {
+                println!("{:?}", e);
+            }
+        }
+
+        for (geo, pos) in (&mut geodetics, &positions).join() {
+            *geo = position_to_geodetic(pos, &origin.origin);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geodetic() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, GeodeticPosition>,
+                ReadStorage<'a, Position>
+            );
+
+            fn run(&mut self, (geodetics, positions): Self::SystemData) {
+                assert_eq!(positions.count(), 1);
+                for (geo, pos) in (&geodetics, &positions).join() {
+                    // A tenth of a degree north of the origin
+                    assert!(pos.x.abs() < 1e-2);
+                    assert!((pos.y - 11_057.0).abs() < 5.0);
+                    assert!((geo.latitude - 0.1).abs() < 1e-6);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Position>();
+        world.register::<GeodeticPosition>();
+
+        // Initialize systems
+        let mut sys = GeodeticSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create target entity given only in geodetic coordinates
+        let _target1 = world.create_entity()
+        .with(GeodeticPosition{
+            latitude: 0.1,
+            longitude: 0.0,
+            altitude: 1000.0
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -0,0 +1,192 @@
+use super::*;
+
+// Combines the intercepts of every ESM platform into emitter fixes.
+// Intercepts on different platforms are taken to be the same emitter when their frequency and PRI agree.
+pub struct GeolocationSystem;
+impl<'a> System<'a> for GeolocationSystem {
+    type SystemData = (
+        ReadStorage<'a, EsmReceiver>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Velocity>,
+        Read<'a, Geolocation>,
+        Write<'a, GeolocationReport>,
+    );
+
+    fn run(&mut self, (esm, positions, velocities, config, mut report) : Self::SystemData) {
+        report.fixes.clear();
+        let sensors: Vec<([f32; 3], [f32; 3], &EsmReceiver)> = (&esm, &positions, velocities.maybe()).join()
+            .map(|(esm, pos, vel)| ([pos.x, pos.y, pos.z], vel.map_or([0.0; 3], |v| [v.x, v.y, v.z]), esm))
+            .collect();
+
+        let mut used: Vec<Vec<bool>> = sensors.iter().map(|s| vec![false; s.2.intercepts.len()]).collect();
+        for first in 0..sensors.len() {
+            for k in 0..sensors[first].2.intercepts.len() {
+                if used[first][k] {
+                    continue;
+                }
+                let intercept = &sensors[first].2.intercepts[k];
+                let same_emitter = |other: &Intercept| {
+                    (other.frequency - intercept.frequency).abs() <= config.frequency_tolerance
+                        && match (other.pri, intercept.pri) {
+                            (Some(a), Some(b)) => (a - b).abs() <= 1e-6 * b,
+                            (None, None) => true,
+                            _ => false,
+                        }
+                };
+                // At most one intercept from each platform
+                let mut group = vec![(first, k)];
+                for (other, sensor) in sensors.iter().enumerate().skip(first + 1) {
+                    let found = sensor.2.intercepts.iter().enumerate()
+                        .find(|(j, candidate)| !used[other][*j] && same_emitter(candidate));
+                    if let Some((j, _)) = found {
+                        group.push((other, j));
+                    }
+                }
+                if group.len() < 2 {
+                    continue;
+                }
+                for (sensor, j) in group.iter() {
+                    used[*sensor][*j] = true;
+                }
+
+                let (reference, reference_velocity, _) = sensors[first];
+                let mut measurements = Vec::new();
+                for (sensor, j) in group.iter() {
+                    let (position, velocity, esm) = sensors[*sensor];
+                    let measured = &esm.intercepts[*j];
+                    if config.bearings {
+                        measurements.push(GeoMeasurement::Bearing{sensor: position, bearing: measured.angle, sigma: esm.df_accuracy});
+                    }
+                    if *sensor == first {
+                        continue;
+                    }
+                    if config.tdoa {
+                        measurements.push(GeoMeasurement::Tdoa{
+                            sensor: position,
+                            reference,
+                            difference: measured.time_of_arrival - intercept.time_of_arrival,
+                            sigma: config.time_accuracy * std::f32::consts::SQRT_2
+                        });
+                    }
+                    if config.fdoa {
+                        measurements.push(GeoMeasurement::Fdoa{
+                            sensor: position,
+                            sensor_velocity: velocity,
+                            reference,
+                            reference_velocity,
+                            frequency: intercept.frequency,
+                            difference: measured.doppler - intercept.doppler,
+                            sigma: config.frequency_accuracy * std::f32::consts::SQRT_2
+                        });
+                    }
+                }
+
+                // Start from where the first two bearings cross, or the middle of the platforms
+                let (second, j) = group[1];
+                let centroid = group.iter().fold((0.0, 0.0), |(x, y), (s, _)| (x + sensors[*s].0[0], y + sensors[*s].0[1]));
+                let initial = bearing_intersection(
+                    (reference[0], reference[1]), intercept.angle,
+                    (sensors[second].0[0], sensors[second].0[1]), sensors[second].2.intercepts[j].angle
+                ).unwrap_or((centroid.0 / group.len() as f32, centroid.1 / group.len() as f32));
+
+                if let Some((x, y, covariance)) = locate_emitter(&measurements, initial, config.emitter_altitude) {
+                    let (semi_major, semi_minor, orientation) = error_ellipse(covariance);
+                    report.fixes.push(EmitterFix{
+                        frequency: intercept.frequency - intercept.doppler,
+                        pri: intercept.pri,
+                        x,
+                        y,
+                        z: config.emitter_altitude,
+                        semi_major,
+                        semi_minor,
+                        orientation,
+                        sensors: group.len()
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geolocation() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = Read<'a, GeolocationReport>;
+
+            fn run(&mut self, report: Self::SystemData) {
+                // The emission only one platform heard cannot be located
+                assert_eq!(report.fixes.len(), 1);
+                let fix = &report.fixes[0];
+                assert_eq!(fix.sensors, 3);
+                assert!((fix.x - 10000.0).abs() < 5.0 && (fix.y - 20000.0).abs() < 5.0);
+                assert!(fix.semi_major >= fix.semi_minor && fix.semi_major < 500.0);
+                assert!((fix.frequency - 3.0e9).abs() < 1.0);
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Position>();
+        world.register::<Velocity>();
+
+        let mut sys = GeolocationSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // What each platform measures of an emitter at (10 km, 20 km) on the ground
+        let emitter = Position{x: 10000.0, y: 20000.0, z: 0.0, direction: 0.0};
+        let intercept = |pos: &Position, vel: &Velocity, frequency: f32| {
+            let range = slant_range(&emitter, pos);
+            let away = (vel.x * (pos.x - emitter.x) + vel.y * (pos.y - emitter.y) + vel.z * (pos.z - emitter.z)) / range;
+            let doppler = -frequency * away / C;
+            Intercept{
+                angle: incident_angle(pos, &emitter),
+                frequency: frequency + doppler,
+                doppler,
+                pri: Some(0.001),
+                pulse_width: Some(1e-6),
+                power: 1e-9,
+                time: range / C,
+                time_of_arrival: range / C
+            }
+        };
+
+        let platforms = [
+            (Position{x: 0.0, y: 0.0, z: 5000.0, direction: 0.0}, Velocity{x: 200.0, y: 0.0, z: 0.0}),
+            (Position{x: 30000.0, y: 0.0, z: 5000.0, direction: 0.0}, Velocity{x: 0.0, y: 200.0, z: 0.0}),
+            (Position{x: 0.0, y: 30000.0, z: 5000.0, direction: 0.0}, Velocity{x: 200.0, y: -200.0, z: 0.0}),
+        ];
+        for (n, (pos, vel)) in platforms.iter().enumerate() {
+            let mut intercepts = vec![intercept(pos, vel, 3.0e9)];
+            if n == 0 {
+                intercepts.push(intercept(pos, vel, 9.0e9));
+            }
+            world.create_entity()
+            .with(Position{x: pos.x, y: pos.y, z: pos.z, direction: 0.0})
+            .with(Velocity{x: vel.x, y: vel.y, z: vel.z})
+            .with(EsmReceiver{
+                gain: 1.0,
+                sensitivity: 1e-12,
+                min_frequency: 1.0e9,
+                max_frequency: 18.0e9,
+                df_accuracy: 1.0,
+                intercepts
+            }).build();
+        }
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -8,32 +8,40 @@ impl<'a> System<'a> for InteractionDetection {
         ReadStorage<'a, EMWave>,
         WriteStorage<'a, TargetIllumination>,
         ReadStorage<'a, RCS>,
-        Entities<'a>,
+        Read<'a, EarthModel>,
+        Read<'a, Terrain>,
+        Read<'a, Weather>,
+        Read<'a, Surface>,
+        ReadStorage<'a, RainCell>,
+        ReadStorage<'a, WaveSource>,
     );
 
-    fn run(&mut self, (positions, emissions, mut illumination, rcs, entities): Self::SystemData) {
-        // Loop through all of the emissions. em_entity is just an identifier
-        for (em_entity, em, em_pos) in (&*entities, &emissions, &positions).join() {
+    fn run(&mut self, (positions, emissions, mut illumination, rcs, earth, terrain, weather, surface, rain_cells, sources): Self::SystemData) {
+        // Loop through all of the emissions. They stay in the world for receivers until WaveCleanupSystem removes them
+        for (em, em_pos, source) in (&emissions, &positions, sources.maybe()).join() {
             // Loops through entities with only a position, illumination, and RCS. 
             // Should just be our 'targets'
             for(targ_rcs, targ_pos, ill) in (&rcs, &positions, &mut illumination).join() {
                 let angle = incident_angle(em_pos, targ_pos);
-                if check_illumination(em.azimuth_width, em_pos.direction, angle) {
-                    let range = calculate_range(&em_pos, &targ_pos);
-                    let power = em.power / (4.0 * std::f32::consts::PI * range.powi(2));
+                if check_illumination(em.azimuth_width, em_pos.direction, angle)
+                    && line_of_sight(em_pos, targ_pos, &earth) && terrain_clear(&terrain, em_pos, targ_pos) {
+                    let range = slant_range(em_pos, targ_pos);
+                    let power = em.power * atmospheric_loss(em.frequency, range, &weather)
+                        * rain_cell_loss(em_pos, targ_pos, em.frequency, (&rain_cells, &positions).join())
+                        * propagation_factor(em_pos, targ_pos, em.wavelength, &surface).powi(2)
+                        / (4.0 * std::f32::consts::PI * range.powi(2));
                     ill.illuminations.push(Illumination{
-                        power: power, 
+                        power, 
                         lambda: em.wavelength, 
                         frequency: em.frequency, 
+                        doppler: em.doppler,
                         angle: (angle + 180.0) % 360.0, // Change angle to target perspective 
-                        rcs: targ_rcs.avg_rcs
+                        elevation: elevation_angle(targ_pos, em_pos),
+                        rcs: targ_rcs.avg_rcs,
+                        source: source.copied()
                     });
                 }
             }
-            match entities.delete(em_entity) {
-                Ok(r) => r,
-                Err(e) => eprintln!("Error!\n {}", e),
-            }
         }
     }
 }
@@ -56,10 +64,13 @@ mod tests {
                     for illum in illums.illuminations.iter() {
                         assert_eq!(illum, &Illumination{
                             angle: 225.0,
+                            elevation: 0.0,
                             frequency: 100.0,
+                            doppler: 0.0,
                             lambda: 100.0,
                             rcs: 180.0,
-                            power: 0.00039788734
+                            power: 0.00039788734,
+                            source: None
                         });
                     }
                 }
@@ -85,6 +96,7 @@ mod tests {
         let _em_wave = world.create_entity()
         .with(EMWave{
             frequency: 100.0,
+            doppler: 0.0,
             power: 100.0,
             wavelength: 100.0,
             azimuth_width: 20.0,
@@ -117,5 +129,144 @@ mod tests {
         // Run test 
         tester.run_now(&world);
     }
-}
 
+    #[test]
+    fn test_radar_horizon() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, TargetIllumination>;
+
+            fn run(&mut self, illuminations: Self::SystemData) {
+                for illums in (&illuminations).join() {
+                    // Low flying target 400 km out is below the horizon
+                    assert_eq!(illums.illuminations.len(), 0);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+
+        let mut sys = InteractionDetection;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create EMWave Entity
+        let _em_wave = world.create_entity()
+        .with(EMWave{
+            frequency: 100.0,
+            doppler: 0.0,
+            power: 100.0,
+            wavelength: 100.0,
+            azimuth_width: 20.0,
+            elevation_width: 10.0
+        }).with(Position{
+            x: 0.0,
+            y: 0.0,
+            z: 10.0,
+            direction: 0.0
+        }).build();
+
+        // Create target entity
+        let _target1 = world.create_entity()
+        .with(Position{
+            x: 400_000.0,
+            y: 0.0,
+            z: 10.0,
+            direction: 0.0
+        }).with(RCS{
+            angles: vec![0.0],
+            values: vec![1.0],
+            avg_rcs: 1.0
+        }).with(TargetIllumination{
+            illuminations: Vec::new()
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+
+    #[test]
+    fn test_terrain_masking() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, TargetIllumination>;
+
+            fn run(&mut self, illuminations: Self::SystemData) {
+                for illums in (&illuminations).join() {
+                    // Target sits behind the ridge
+                    assert_eq!(illums.illuminations.len(), 0);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+
+        // 500 m ridge running north-south between the radar and target
+        world.insert(Terrain{
+            ncols: 3,
+            nrows: 1,
+            x_origin: 0.0,
+            y_origin: 0.0,
+            cell_size: 1000.0,
+            heights: vec![0.0, 500.0, 0.0]
+        });
+
+        let mut sys = InteractionDetection;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create EMWave Entity
+        let _em_wave = world.create_entity()
+        .with(EMWave{
+            frequency: 100.0,
+            doppler: 0.0,
+            power: 100.0,
+            wavelength: 100.0,
+            azimuth_width: 20.0,
+            elevation_width: 10.0
+        }).with(Position{
+            x: 0.0,
+            y: 0.0,
+            z: 10.0,
+            direction: 0.0
+        }).build();
+
+        // Create target entity
+        let _target1 = world.create_entity()
+        .with(Position{
+            x: 2000.0,
+            y: 0.0,
+            z: 10.0,
+            direction: 0.0
+        }).with(RCS{
+            angles: vec![0.0],
+            values: vec![1.0],
+            avg_rcs: 1.0
+        }).with(TargetIllumination{
+            illuminations: Vec::new()
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -7,44 +7,67 @@ impl<'a> System<'a> for JammingSystem {
         WriteStorage<'a, EMWave>,
         WriteStorage<'a, Position>,
         ReadStorage <'a, Antenna>,
+        ReadStorage<'a, Jammer>,
+        WriteStorage<'a, NoiseJamming>,
+        WriteStorage<'a, WaveSource>,
         Entities<'a>,
     );
 
-    fn run(&mut self, (target_illumination, mut emission, mut position, antenna, entities) : Self::SystemData) {
+    fn run(&mut self, (target_illumination, mut emission, mut position, antenna, jammers, mut noise, mut sources, entities) : Self::SystemData) {
         
         let mut new_positions: Vec<Position> = Vec::new();
         let mut new_emissions: Vec<EMWave> = Vec::new();
+        let mut new_noise: Vec<Option<(Entity, NoiseJamming)>> = Vec::new();
         // Iterate through each target
-        for (target, pos, ant) in (&target_illumination, &mut position, &antenna).join() {
-            for _ill in target.illuminations.iter() {
+        for (jammer_entity, target, pos, ant, jammer) in (&*entities, &target_illumination, &mut position, &antenna, jammers.maybe()).join() {
+            for ill in target.illuminations.iter() {
+                let (direction, jamming) = match jammer {
+                    // Noise jammers point back at the radar illuminating them
+                    Some(jammer) => {
+                        let center_frequency = match jammer.mode {
+                            JammingMode::Barrage => jammer.center_frequency,
+                            JammingMode::Spot => ill.frequency,
+                        };
+                        (ill.angle, Some(NoiseJamming{center_frequency, bandwidth: jammer.bandwidth}))
+                    },
+                    None => (pos.direction, None),
+                };
                 let position = Position{
                     x: pos.x, 
                     y: pos.y, 
                     z: pos.z, 
-                    direction: pos.direction
+                    direction
                 };
                 let emission = EMWave{
                     power: (ant.power*ant.gain), 
-                    wavelength: ant.wavelength, 
-                    frequency: ant.frequency, 
+                    wavelength: jamming.map_or(ant.wavelength, |j| C / j.center_frequency), 
+                    frequency: jamming.map_or(ant.frequency, |j| j.center_frequency), 
+                    doppler: 0.0,
                     azimuth_width: ant.azimuth_beam_width, 
                     elevation_width: ant.elevation_beam_width
                 };
                     
                 new_positions.push(position);
                 new_emissions.push(emission);
+                new_noise.push(jamming.map(|j| (jammer_entity, j)));
             }
         }
 
-        while new_positions.len() != 0 {
+        while !new_positions.is_empty() {
             let new_entity = entities.create();
-            match position.insert(new_entity, new_positions.remove(0)) {
-                Err(e) => println!("{:?}", e),
-                _ => ()
+            if let Err(e) = position.insert(new_entity, new_positions.remove(0)) {
+                println!("{:?}", e);
+            }
+            if let Err(e) = emission.insert(new_entity, new_emissions.remove(0)) {
+                println!("{:?}", e);
             }
-            match emission.insert(new_entity, new_emissions.remove(0)) {
-                Err(e) => println!("{:?}", e),
-                _ => ()
+            if let Some((jammer_entity, jamming)) = new_noise.remove(0) {
+                if let Err(e) = noise.insert(new_entity, jamming) {
+                    println!("{:?}", e);
+                }
+                if let Err(e) = sources.insert(new_entity, WaveSource{emitter: jammer_entity, waveform_id: 0, reflector: None, bounces: 0}) {
+                    println!("{:?}", e);
+                }
             }
         }
     }
@@ -69,6 +92,7 @@ mod tests {
                         power: 100.0, 
                         wavelength: ((3.0 * 100000000.0) / 100.0), 
                         frequency: 100.0, 
+                        doppler: 0.0,
                         azimuth_width: 10.0, 
                         elevation_width: 20.0
                     });
@@ -108,10 +132,13 @@ mod tests {
         }).with(TargetIllumination{
             illuminations: vec![Illumination{
                 angle: 90.0,
+                elevation: 0.0,
                 frequency: 10.0,
+                doppler: 0.0,
                 lambda: 100.0,
                 rcs: 1.0,
-                power: 10.0
+                power: 10.0,
+                source: None
             }]
         }).build();
 
@@ -121,4 +148,77 @@ mod tests {
         // Run test 
         tester.run_now(&world);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_noise_jamming() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, EMWave>,
+                ReadStorage<'a, Position>,
+                ReadStorage<'a, NoiseJamming>
+            );
+
+            fn run(&mut self, (em_waves, positions, noise): Self::SystemData) {
+                assert_eq!(noise.count(), 1);
+                for (em_wave, pos, jamming) in (&em_waves, &positions, &noise).join() {
+                    // Spot noise set on to the illuminating radar and pointed back at it
+                    assert_eq!(jamming, &NoiseJamming{center_frequency: 9.4e9, bandwidth: 10e6});
+                    assert_eq!(em_wave.frequency, 9.4e9);
+                    assert_eq!(pos.direction, 225.0);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<Antenna>();
+
+        let mut sys = JammingSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create jammer entity being illuminated
+        let _jammer = world.create_entity()
+        .with(Position{
+            x: 100.0,
+            y: 100.0,
+            z: 0.0,
+            direction: 0.0
+        }).with(Antenna{
+            frequency: 9.0e9,
+            gain: 10.0,
+            power: 10.0,
+            wavelength: C / 9.0e9,
+            azimuth_beam_width: 30.0,
+            elevation_beam_width: 30.0
+        }).with(Jammer{
+            mode: JammingMode::Spot,
+            center_frequency: 0.0,
+            bandwidth: 10e6
+        }).with(TargetIllumination{
+            illuminations: vec![Illumination{
+                angle: 225.0,
+                elevation: 0.0,
+                frequency: 9.4e9,
+                doppler: 0.0,
+                lambda: C / 9.4e9,
+                rcs: 1.0,
+                power: 10.0,
+                source: None
+            }]
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -0,0 +1,130 @@
+use super::*;
+
+// Assesses each noise jammer against every radar whose band it covers:
+// self-screening J/S at the current range and the burn-through range
+pub struct JammingAnalysisSystem;
+impl<'a> System<'a> for JammingAnalysisSystem {
+    type SystemData = (
+        ReadStorage<'a, Jammer>,
+        ReadStorage<'a, Antenna>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, RCS>,
+        ReadStorage<'a, Waveform>,
+        WriteStorage<'a, JammingReport>,
+        Entities<'a>,
+    );
+
+    fn run(&mut self, (jammers, antennas, positions, cross_sections, waveforms, mut reports, entities): Self::SystemData) {
+        for (jammer, jam_ant, jam_pos, rcs, report) in (&jammers, &antennas, &positions, &cross_sections, &mut reports).join() {
+            report.assessments.clear();
+            // Radars are the antennas that are not jammers themselves
+            for (radar, radar_ant, radar_pos, waveform, _) in (&*entities, &antennas, &positions, waveforms.maybe(), !&jammers).join() {
+                let center_frequency = match jammer.mode {
+                    JammingMode::Barrage => jammer.center_frequency,
+                    JammingMode::Spot => radar_ant.frequency,
+                };
+                let noise = NoiseJamming{center_frequency, bandwidth: jammer.bandwidth};
+                let fraction = in_band_fraction(&noise, radar_ant.frequency, radar_bandwidth(waveform));
+                if fraction <= 0.0 {
+                    continue;
+                }
+                let radar_erp = radar_ant.power * radar_ant.gain;
+                let jammer_erp = jam_ant.power * jam_ant.gain;
+                let range = slant_range(radar_pos, jam_pos);
+                report.assessments.push(JammingAssessment{
+                    radar,
+                    range,
+                    jamming_to_signal: self_screening_js(radar_erp, jammer_erp, fraction, rcs.avg_rcs, range),
+                    burn_through_range: burn_through_range(radar_erp, jammer_erp, fraction, rcs.avg_rcs)
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jamming_analysis() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, JammingReport>;
+
+            fn run(&mut self, reports: Self::SystemData) {
+                for report in (&reports).join() {
+                    // Only the radar inside the barrage band is jammed
+                    assert_eq!(report.assessments.len(), 1);
+                    let assessment = &report.assessments[0];
+                    assert_eq!(assessment.range, 50_000.0);
+                    // Jammed well outside burn-through
+                    assert!(assessment.jamming_to_signal > 1.0);
+                    assert!(assessment.burn_through_range < assessment.range);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Antenna>();
+        world.register::<Position>();
+        world.register::<RCS>();
+
+        // Initialize systems
+        let mut sys = JammingAnalysisSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let radar = |frequency: f32| Antenna{
+            frequency,
+            gain: 1000.0,
+            power: 100_000.0,
+            wavelength: C / frequency,
+            azimuth_beam_width: 2.0,
+            elevation_beam_width: 20.0
+        };
+
+        // Create radar entities, one inside and one outside the jamming band
+        let _x_band = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(radar(9.4e9))
+        .with(Waveform{id: 0, prf: 1000.0, pulse_width: 1e-6, pulses: 16, stagger: Vec::new()})
+        .build();
+        let _s_band = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(radar(3.0e9))
+        .build();
+
+        // Create jammer entity
+        let _jammer = world.create_entity()
+        .with(Position{x: 50_000.0, y: 0.0, z: 10.0, direction: 0.0})
+        .with(Antenna{
+            frequency: 9.5e9,
+            gain: 10.0,
+            power: 100.0,
+            wavelength: C / 9.5e9,
+            azimuth_beam_width: 60.0,
+            elevation_beam_width: 60.0
+        }).with(Jammer{
+            mode: JammingMode::Barrage,
+            center_frequency: 9.5e9,
+            bandwidth: 1e9
+        }).with(RCS{
+            angles: vec![0.0],
+            values: vec![5.0],
+            avg_rcs: 5.0
+        }).with(JammingReport::default())
+        .build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -1,22 +1,51 @@
 use super::*;
 
-// Changes the position of each entity with position and velocity
+// Changes the position of each entity with position and velocity,
+// using the entity's MotionModel when it has one
 pub struct Movement;
 impl<'a> System<'a> for Movement {
 
     type SystemData = (
         WriteStorage<'a, Position>,
         WriteStorage<'a, Antenna>,
-        ReadStorage<'a, Velocity>,
+        WriteStorage<'a, Boresight>,
+        ReadStorage<'a, Scan>,
+        WriteStorage<'a, Velocity>,
+        WriteStorage<'a, Acceleration>,
+        ReadStorage<'a, MotionModel>,
+        Read<'a, SimulationTime>,
+        Write<'a, SimulationRng>,
     );
 
-    fn run(&mut self, (mut position, mut sensor, velocity): Self::SystemData) {
-        for(pos, sen) in (&mut position, &mut sensor).join() {
-            rotate_entity(pos, sen);
+    fn run(&mut self, (mut position, mut sensor, mut boresights, scans, mut velocity, mut acceleration, models, time, mut rng): Self::SystemData) {
+        for(pos, sen, bore, scan) in (&mut position, &mut sensor, (&mut boresights).maybe(), scans.maybe()).join() {
+            let step = scan_step(scan, sen, time.delta);
+            match bore {
+                // Antennas with a boresight scan relative to their platform
+                Some(bore) => rotate_boresight(bore, step),
+                None => rotate_entity(pos, step),
+            }
         }
 
-        for(pos, vel) in (&mut position, &velocity).join() {
-            move_entity(pos, vel);
+        let no_acceleration = Acceleration{x: 0.0, y: 0.0, z: 0.0};
+        for(pos, vel, acc, model) in (&mut position, &mut velocity, (&mut acceleration).maybe(), models.maybe()).join() {
+            match model {
+                None | Some(MotionModel::ConstantVelocity) => move_entity(pos, vel, time.delta),
+                Some(MotionModel::ConstantAcceleration) => {
+                    accelerate_entity(pos, vel, acc.map_or(&no_acceleration, |a| &*a), time.delta);
+                },
+                Some(MotionModel::CoordinatedTurn{turn_rate}) => {
+                    coordinated_turn(pos, vel, *turn_rate, time.delta);
+                },
+                Some(MotionModel::Singer{tau, sigma}) => match acc {
+                    Some(acc) => {
+                        accelerate_entity(pos, vel, acc, time.delta);
+                        singer_acceleration(acc, *tau, *sigma, time.delta, &mut rng.rng);
+                    },
+                    // Without an Acceleration component there is nowhere to keep the maneuver state
+                    None => move_entity(pos, vel, time.delta),
+                },
+            }
         }
     }
 }
@@ -105,4 +134,70 @@ mod tests {
         // Run test 
         tester.run_now(&world);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_motion_models() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, Position>,
+                ReadStorage<'a, Velocity>,
+                ReadStorage<'a, MotionModel>
+            );
+
+            fn run(&mut self, (positions, velocities, models): Self::SystemData) {
+                assert_eq!(models.count(), 2);
+                for (pos, vel, model) in (&positions, &velocities, &models).join() {
+                    match model {
+                        MotionModel::ConstantAcceleration => {
+                            assert_eq!(pos, &Position{x: 11.0, y: 0.0, z: 0.0, direction: 0.0});
+                            assert_eq!(vel, &Velocity{x: 12.0, y: 0.0, z: 0.0});
+                        },
+                        MotionModel::CoordinatedTurn{..} => {
+                            assert!(vel.x.abs() < 1e-3);
+                            assert!((vel.y - 10.0).abs() < 1e-3);
+                        },
+                        _ => panic!("unexpected motion model"),
+                    }
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Acceleration>();
+        world.register::<MotionModel>();
+
+        // Initialize systems
+        let mut sys = Movement;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Accelerating target
+        let _target1 = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0})
+        .with(Velocity{x: 10.0, y: 0.0, z: 0.0})
+        .with(Acceleration{x: 2.0, y: 0.0, z: 0.0})
+        .with(MotionModel::ConstantAcceleration)
+        .build();
+
+        // Target turning 90 degrees in one tick
+        let _target2 = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0})
+        .with(Velocity{x: 10.0, y: 0.0, z: 0.0})
+        .with(MotionModel::CoordinatedTurn{turn_rate: 90.0})
+        .build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -5,12 +5,18 @@ impl<'a> System<'a> for RCSSystem {
     type SystemData = (
         ReadStorage<'a, RCS>,
         WriteStorage<'a, TargetIllumination>,
+        ReadStorage<'a, Orientation>,
     );
 
-    fn run(&mut self, (cross_sections, mut illuminations) : Self::SystemData)  {
-        for (rcs, targ) in (&cross_sections, &mut illuminations).join() {
+    fn run(&mut self, (cross_sections, mut illuminations, orientations) : Self::SystemData)  {
+        for (rcs, targ, orient) in (&cross_sections, &mut illuminations, orientations.maybe()).join() {
             for ill in targ.illuminations.iter_mut() {
-                ill.rcs = calculate_rcs(ill.angle, &rcs.angles, &rcs.values);
+                // Targets with an attitude are looked up by aspect in their own body frame
+                let aspect = match orient {
+                    Some(orient) => body_aspect(orient, ill.angle, ill.elevation).0,
+                    None => ill.angle,
+                };
+                ill.rcs = calculate_rcs(aspect, &rcs.angles, &rcs.values);
             }
         }
     }
@@ -33,10 +39,13 @@ mod tests {
                     for illum in illums.illuminations.iter() {
                         assert_eq!(illum, &Illumination{
                             angle: 90.0,
+                            elevation: 0.0,
                             frequency: 10.0,
+                            doppler: 0.0,
                             lambda: 100.0,
                             rcs: 90.0,
-                            power: 10.0
+                            power: 10.0,
+                            source: None
                         });
                     }
                 }
@@ -68,10 +77,13 @@ mod tests {
         .with(TargetIllumination{
             illuminations: vec![Illumination{
                 angle: 90.0,
+                elevation: 0.0,
                 frequency: 10.0,
+                doppler: 0.0,
                 lambda: 100.0,
                 rcs: 1.0,
-                power: 10.0
+                power: 10.0,
+                source: None
             }]
         }).build();
 
@@ -81,4 +93,65 @@ mod tests {
         // Run test 
         tester.run_now(&world);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rcs_body_frame() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, TargetIllumination>;
+
+            fn run(&mut self, illuminations: Self::SystemData) {
+                for illums in (&illuminations).join() {
+                    // Wave from world 180 degrees hits a target yawed to 90 degrees on its 90 degree aspect
+                    assert_eq!(illums.illuminations[0].rcs, 90.0);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<RCS>();
+        world.register::<TargetIllumination>();
+        world.register::<Orientation>();
+
+        // Initialize systems
+        let mut sys = RCSSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create illumination entity
+        let _target_illum = world.create_entity()
+        .with(RCS{
+            angles: vec![0.0, 90.0, 180.0, 270.0],
+            values: vec![0.0, 90.0, 180.0, 270.0],
+            avg_rcs: 180.0
+        })
+        .with(Orientation{
+            yaw: 90.0,
+            pitch: 0.0,
+            roll: 0.0
+        })
+        .with(TargetIllumination{
+            illuminations: vec![Illumination{
+                angle: 180.0,
+                elevation: 0.0,
+                frequency: 10.0,
+                doppler: 0.0,
+                lambda: 100.0,
+                rcs: 1.0,
+                power: 10.0,
+                source: None
+            }]
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
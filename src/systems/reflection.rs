@@ -7,35 +7,41 @@ impl<'a> System<'a> for ReflectionSystem {
         WriteStorage<'a, TargetIllumination>,
         WriteStorage<'a, EMWave>,
         WriteStorage<'a, Position>,
+        WriteStorage<'a, WaveSource>,
         Entities<'a>,
     );
 
-    fn run(&mut self, (mut target_illumination, mut emission, mut position, entities) : Self::SystemData) {
+    fn run(&mut self, (mut target_illumination, mut emission, mut position, mut sources, entities) : Self::SystemData) {
         
         let mut new_positions: Vec<Position> = Vec::new();
         let mut new_emissions: Vec<EMWave> = Vec::new();
+        let mut new_sources: Vec<Option<WaveSource>> = Vec::new();
         // Iterate through each target
-        for (target, pos) in (&mut target_illumination, &position).join() {
+        for (reflector, target, pos) in (&*entities, &mut target_illumination, &position).join() {
             for ill in target.illuminations.iter() {
                 let position = Position{x: pos.x, y: pos.y, z: pos.z, direction: ill.angle};
                 let p_r = ill.power * ill.rcs;
-                let emission = EMWave{power: p_r, wavelength: ill.lambda, frequency: ill.frequency, azimuth_width: 20.0, elevation_width: 20.0};
+                let emission = EMWave{power: p_r, wavelength: ill.lambda, frequency: ill.frequency, doppler: ill.doppler, azimuth_width: 20.0, elevation_width: 20.0};
                 // println!("Emission Direction: {}", position.direction);
                 new_positions.push(position);
                 new_emissions.push(emission);
+                new_sources.push(ill.source.map(|source| WaveSource{reflector: Some(reflector), bounces: source.bounces + 1, ..source}));
             }
             target.illuminations.clear();
         }
 
-        while new_positions.len() != 0 {
+        while !new_positions.is_empty() {
             let new_entity = entities.create();
-            match position.insert(new_entity, new_positions.remove(0)) {
-                Err(e) => println!("{:?}", e),
-                _ => ()
+            if let Err(e) = position.insert(new_entity, new_positions.remove(0)) {
+                println!("{:?}", e);
             }
-            match emission.insert(new_entity, new_emissions.remove(0)) {
-                Err(e) => println!("{:?}", e),
-                _ => ()
+            if let Err(e) = emission.insert(new_entity, new_emissions.remove(0)) {
+                println!("{:?}", e);
+            }
+            if let Some(source) = new_sources.remove(0) {
+                if let Err(e) = sources.insert(new_entity, source) {
+                    println!("{:?}", e);
+                }
             }
         }
     }
@@ -62,6 +68,7 @@ mod tests {
                         power: 10.0, 
                         wavelength: 100.0, 
                         frequency: 10.0, 
+                        doppler: 0.0,
                         azimuth_width: 20.0, 
                         elevation_width: 20.0
                     });
@@ -102,10 +109,13 @@ mod tests {
         .with(TargetIllumination{
             illuminations: vec![Illumination{
                 angle: 90.0,
+                elevation: 0.0,
                 frequency: 10.0,
+                doppler: 0.0,
                 lambda: 100.0,
                 rcs: 1.0,
-                power: 10.0
+                power: 10.0,
+                source: None
             }]
         }).build();
 
@@ -0,0 +1,112 @@
+use super::*;
+
+// Applies each radar's MTI canceller and pulse-Doppler filter bank to the signals it received
+pub struct SignalProcessingSystem;
+impl<'a> System<'a> for SignalProcessingSystem {
+    type SystemData = (
+        WriteStorage<'a, Receiver>,
+        ReadStorage<'a, SignalProcessor>,
+        ReadStorage<'a, Waveform>,
+        ReadStorage<'a, Antenna>,
+    );
+
+    fn run(&mut self, (mut receivers, processors, waveforms, antennas): Self::SystemData) {
+        for (receiver, processor, waveform, antenna) in (&mut receivers, &processors, &waveforms, &antennas).join() {
+            for signal in receiver.signals.iter_mut().chain(receiver.interference_targets.iter_mut()) {
+                signal.processing_gain = processing_gain(processor, waveform, signal.doppler);
+            }
+            // The search for the blind speed is slow, so it only runs again when the processor or waveform changes
+            let stale = receiver.processed_for.as_ref().is_none_or(|(p, w)| p != processor || w != waveform);
+            if stale {
+                receiver.improvement_factor = improvement_factor(processor, waveform);
+                receiver.first_blind_doppler = first_blind_doppler(processor, waveform);
+                receiver.processed_for = Some((processor.clone(), waveform.clone()));
+            }
+            // Frequency agility moves the carrier every dwell, which only rescales the blind speed
+            receiver.first_blind_speed = receiver.first_blind_doppler.map(|doppler| doppler * antenna.wavelength / 2.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signal_processing() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = ReadStorage<'a, Receiver>;
+
+            fn run(&mut self, receivers: Self::SystemData) {
+                for receiver in (&receivers).join() {
+                    // Stationary clutter is cancelled, the mover at PRF / 2 passes
+                    assert!(receiver.signals[0].processing_gain < 1e-6);
+                    assert!((receiver.signals[1].processing_gain - 2.0).abs() < 1e-3);
+                    assert!(receiver.improvement_factor > 1.0);
+                    assert!((receiver.first_blind_speed.unwrap() - 15.0).abs() < 0.1);
+                    // Kept until the processor or waveform changes
+                    assert!((receiver.first_blind_doppler.unwrap() - 100.0).abs() < 0.5);
+                    assert!(receiver.processed_for.as_ref().is_some_and(|(_, waveform)| waveform.prf == 100.0));
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Antenna>();
+        world.register::<Receiver>();
+        world.register::<SignalProcessor>();
+        world.register::<Waveform>();
+
+        // Initialize systems
+        let mut sys = SignalProcessingSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let signal = |doppler: f32| ReceivedSignal{
+            power: 1.0,
+            range: 1000.0,
+            angle: 0.0,
+            frequency: 1e9 + doppler,
+            doppler,
+            processing_gain: 1.0,
+            jamming_to_signal: 0.0,
+            bistatic_angle: 0.0
+        };
+
+        // Create radar entity
+        let _radar = world.create_entity()
+        .with(Antenna{
+            frequency: 1e9,
+            gain: 1.0,
+            power: 1.0,
+            wavelength: 0.3,
+            azimuth_beam_width: 10.0,
+            elevation_beam_width: 20.0
+        }).with(Waveform{
+            id: 0,
+            prf: 100.0,
+            pulse_width: 1e-6,
+            pulses: 16,
+            stagger: Vec::new()
+        }).with(SignalProcessor{
+            mti: MtiFilter::TwoPulse,
+            doppler_filters: 0,
+            clutter_spread: 1.0
+        }).with(Receiver{
+            signals: vec![signal(0.0), signal(50.0)],
+            ..Receiver::default()
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -0,0 +1,185 @@
+use super::*;
+
+// Stand-off and escort jammers transmit every tick toward their victims, whether or not they are illuminated
+pub struct StandOffJammingSystem;
+impl<'a> System<'a> for StandOffJammingSystem {
+    type SystemData = (
+        ReadStorage<'a, StandOffJammer>,
+        ReadStorage<'a, Jammer>,
+        ReadStorage<'a, Antenna>,
+        ReadStorage<'a, TargetIllumination>,
+        WriteStorage<'a, EMWave>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, NoiseJamming>,
+        WriteStorage<'a, WaveSource>,
+        Entities<'a>,
+    );
+
+    fn run(&mut self, (stand_off, jammers, antennas, target_illumination, mut emission, mut position, mut noise, mut sources, entities) : Self::SystemData) {
+
+        let mut new_positions: Vec<Position> = Vec::new();
+        let mut new_emissions: Vec<EMWave> = Vec::new();
+        let mut new_noise: Vec<(Entity, NoiseJamming)> = Vec::new();
+        for (jammer_entity, stand_off, jammer, ant, pos) in (&*entities, &stand_off, &jammers, &antennas, &position).join() {
+            // Each threat is a pointing direction, beam width, radar frequency and priority
+            let mut threats: Vec<(f32, f32, f32, f32)> = Vec::new();
+            for victim in stand_off.victims.iter() {
+                match victim.target {
+                    JammerTarget::Radar(radar) => {
+                        if let Some(radar_pos) = position.get(radar) {
+                            let frequency = antennas.get(radar).map_or(jammer.center_frequency, |a| a.frequency);
+                            threats.push((incident_angle(pos, radar_pos), ant.azimuth_beam_width, frequency, victim.priority));
+                        }
+                    },
+                    JammerTarget::Sector{azimuth, width} => {
+                        threats.push((azimuth, width, jammer.center_frequency, victim.priority));
+                    },
+                }
+            }
+            // Escorts fly close to the package, so they jam along the bearing the package sees each radar on
+            for protected in stand_off.protected.iter() {
+                if let Some(illuminated) = target_illumination.get(*protected) {
+                    for ill in illuminated.illuminations.iter() {
+                        threats.push((ill.angle, ant.azimuth_beam_width, ill.frequency, stand_off.escort_priority));
+                    }
+                }
+            }
+
+            let priorities: Vec<f32> = threats.iter().map(|t| t.3).collect();
+            let powers = allocate_jammer_power(ant.power * ant.gain, &priorities, stand_off.max_beams);
+            for ((direction, width, radar_frequency, _), power) in threats.into_iter().zip(powers) {
+                if power <= 0.0 {
+                    continue;
+                }
+                let center_frequency = match jammer.mode {
+                    JammingMode::Barrage => jammer.center_frequency,
+                    JammingMode::Spot => radar_frequency,
+                };
+                new_positions.push(Position{x: pos.x, y: pos.y, z: pos.z, direction});
+                new_emissions.push(EMWave{
+                    power,
+                    wavelength: C / center_frequency,
+                    frequency: center_frequency,
+                    doppler: 0.0,
+                    azimuth_width: width,
+                    elevation_width: ant.elevation_beam_width
+                });
+                new_noise.push((jammer_entity, NoiseJamming{center_frequency, bandwidth: jammer.bandwidth}));
+            }
+        }
+
+        while !new_positions.is_empty() {
+            let new_entity = entities.create();
+            if let Err(e) = position.insert(new_entity, new_positions.remove(0)) {
+                println!("{:?}", e);
+            }
+            if let Err(e) = emission.insert(new_entity, new_emissions.remove(0)) {
+                println!("{:?}", e);
+            }
+            let (jammer_entity, jamming) = new_noise.remove(0);
+            if let Err(e) = noise.insert(new_entity, jamming) {
+                println!("{:?}", e);
+            }
+            if let Err(e) = sources.insert(new_entity, WaveSource{emitter: jammer_entity, waveform_id: 0, reflector: None, bounces: 0}) {
+                println!("{:?}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stand_off_jamming() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, EMWave>,
+                ReadStorage<'a, Position>,
+                ReadStorage<'a, NoiseJamming>
+            );
+
+            fn run(&mut self, (em_waves, positions, noise): Self::SystemData) {
+                // The sector has the lowest priority and loses its beam
+                assert_eq!(noise.count(), 2);
+                let mut beams: Vec<(f32, f32, f32)> = (&em_waves, &positions, &noise).join()
+                    .map(|(em, pos, jamming)| (pos.direction, em.power, jamming.center_frequency))
+                    .collect();
+                beams.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                // Escort beam along the protected entity's illumination, spot jamming its frequency
+                assert_eq!(beams[0], (45.0, 25.0, 3.0e9));
+                // Stand-off beam pointed at the radar entity
+                assert_eq!(beams[1], (180.0, 75.0, 9.0e9));
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<Antenna>();
+        world.register::<TargetIllumination>();
+
+        let mut sys = StandOffJammingSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let antenna = |frequency: f32| Antenna{
+            frequency,
+            gain: 10.0,
+            power: 10.0,
+            wavelength: C / frequency,
+            azimuth_beam_width: 10.0,
+            elevation_beam_width: 10.0
+        };
+
+        let radar = world.create_entity()
+        .with(Position{x: -10000.0, y: 0.0, z: 0.0, direction: 0.0})
+        .with(antenna(9.0e9))
+        .build();
+
+        let package = world.create_entity()
+        .with(Position{x: 0.0, y: 100.0, z: 0.0, direction: 0.0})
+        .with(TargetIllumination{
+            illuminations: vec![Illumination{
+                angle: 45.0,
+                elevation: 0.0,
+                frequency: 3.0e9,
+                doppler: 0.0,
+                lambda: C / 3.0e9,
+                rcs: 1.0,
+                power: 1.0,
+                source: None
+            }]
+        }).build();
+
+        let _jammer = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0})
+        .with(antenna(9.0e9))
+        .with(Jammer{
+            mode: JammingMode::Spot,
+            center_frequency: 5.0e9,
+            bandwidth: 10e6
+        }).with(StandOffJammer{
+            victims: vec![
+                Victim{target: JammerTarget::Radar(radar), priority: 3.0},
+                Victim{target: JammerTarget::Sector{azimuth: 90.0, width: 30.0}, priority: 0.5},
+            ],
+            protected: vec![package],
+            escort_priority: 1.0,
+            max_beams: 2
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -0,0 +1,85 @@
+use super::*;
+
+// Keeps towed decoys at the end of their cable behind the towing entity
+pub struct TowedDecoySystem;
+impl<'a> System<'a> for TowedDecoySystem {
+    type SystemData = (
+        ReadStorage<'a, TowedDecoy>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, Velocity>,
+        Entities<'a>,
+    );
+
+    fn run(&mut self, (towed, mut positions, mut velocities, entities) : Self::SystemData) {
+        let mut updates: Vec<(Entity, Position, Velocity)> = Vec::new();
+        for (entity, decoy) in (&*entities, &towed).join() {
+            if let (Some(tow_pos), Some(tow_vel)) = (positions.get(decoy.tow), velocities.get(decoy.tow)) {
+                updates.push((
+                    entity,
+                    towed_position(tow_pos, tow_vel, decoy.cable_length),
+                    Velocity{x: tow_vel.x, y: tow_vel.y, z: tow_vel.z}
+                ));
+            }
+        }
+
+        for (entity, pos, vel) in updates {
+            if let Err(e) = positions.insert(entity, pos) {
+                println!("{:?}", e);
+            }
+            if let Err(e) = velocities.insert(entity, vel) {
+                println!("{:?}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_towed_decoy() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, TowedDecoy>,
+                ReadStorage<'a, Position>
+            );
+
+            fn run(&mut self, (towed, positions): Self::SystemData) {
+                for (_decoy, pos) in (&towed, &positions).join() {
+                    assert_eq!(pos, &Position{x: 400.0, y: 0.0, z: 1000.0, direction: 0.0});
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Position>();
+        world.register::<Velocity>();
+
+        let mut sys = TowedDecoySystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        let tow = world.create_entity()
+        .with(Position{x: 500.0, y: 0.0, z: 1000.0, direction: 0.0})
+        .with(Velocity{x: 250.0, y: 0.0, z: 0.0})
+        .build();
+
+        let _decoy = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0})
+        .with(TowedDecoy{tow, cable_length: 100.0})
+        .build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+
+// Steers the velocity of each entity with a trajectory towards its next waypoint
+pub struct TrajectorySystem;
+impl<'a> System<'a> for TrajectorySystem {
+    type SystemData = (
+        WriteStorage<'a, Trajectory>,
+        ReadStorage<'a, Position>,
+        WriteStorage<'a, Velocity>,
+        Read<'a, SimulationTime>,
+    );
+
+    fn run(&mut self, (mut trajectories, positions, mut velocities, time): Self::SystemData) {
+        for (traj, pos, vel) in (&mut trajectories, &positions, &mut velocities).join() {
+            if traj.waypoints.is_empty() {
+                continue;
+            }
+            // Advance past any waypoints we have reached
+            let mut checked = 0;
+            while traj.current < traj.waypoints.len() && checked < traj.waypoints.len() {
+                let wp = &traj.waypoints[traj.current];
+                let dist = ((wp.x - pos.x).powi(2) + (wp.y - pos.y).powi(2) + (wp.z - pos.z).powi(2)).sqrt();
+                if dist > traj.capture_radius {
+                    break;
+                }
+                traj.current += 1;
+                if traj.cyclic && traj.current == traj.waypoints.len() {
+                    traj.current = 0;
+                }
+                checked += 1;
+            }
+            // Past the final waypoint, keep flying the last heading
+            if traj.current >= traj.waypoints.len() {
+                continue;
+            }
+
+            let speed = (vel.x.powi(2) + vel.y.powi(2) + vel.z.powi(2)).sqrt();
+            let max_turn = max_turn_rate(traj, speed) * time.delta;
+            *vel = steer_velocity(pos, vel, &traj.waypoints[traj.current], max_turn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trajectory() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, Trajectory>,
+                ReadStorage<'a, Velocity>
+            );
+
+            fn run(&mut self, (trajectories, velocities): Self::SystemData) {
+                for (traj, vel) in (&trajectories, &velocities).join() {
+                    // First waypoint was captured, now turning towards the second at 10 deg/s
+                    assert_eq!(traj.current, 1);
+                    assert!((vel.y.atan2(vel.x).to_degrees() - 10.0).abs() < 1e-3);
+                    assert!(((vel.x.powi(2) + vel.y.powi(2)).sqrt() - 150.0).abs() < 1e-3);
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Trajectory>();
+
+        // Initialize systems
+        let mut sys = TrajectorySystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create target entity sitting on its first waypoint
+        let _target1 = world.create_entity()
+        .with(Position{
+            x: 1000.0,
+            y: 0.0,
+            z: 0.0,
+            direction: 0.0
+        }).with(Velocity{
+            x: 100.0,
+            y: 0.0,
+            z: 0.0
+        }).with(Trajectory{
+            waypoints: vec![
+                Waypoint{x: 1000.0, y: 0.0, z: 0.0, speed: 100.0},
+                Waypoint{x: 1000.0, y: 5000.0, z: 0.0, speed: 150.0},
+            ],
+            current: 0,
+            max_turn_rate: Some(10.0),
+            max_g: None,
+            capture_radius: 50.0,
+            cyclic: false
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}
@@ -5,33 +5,50 @@ impl<'a> System<'a> for TransmitSignal {
     type SystemData = (
         ReadStorage<'a, Antenna>,
         WriteStorage<'a, Position>,
+        ReadStorage<'a, Orientation>,
+        ReadStorage<'a, Boresight>,
+        ReadStorage<'a, Waveform>,
+        ReadStorage<'a, Bistatic>,
+        ReadStorage<'a, Jammer>,
         Entities<'a>,
         Read<'a, LazyUpdate>
     );
 
     fn run(&mut self, 
-        (antennas,  mut positions, entities, updater): Self::SystemData) {
+        (antennas,  mut positions, orientations, boresights, waveforms, bistatic, jammers, entities, updater): Self::SystemData) {
 
         // Must Read from each radar system and save values, 
         // then create the new emission afterwards
         // because we cannot iterate over positions and write to them at the same time.
-        for (ant, pos) in (&antennas, &mut positions).join() {
+        // Bistatic receivers only listen, and jammers radiate noise through their own systems
+        for (radar, ant, pos, orient, bore, waveform, _, _) in (&*entities, &antennas, &mut positions, orientations.maybe(), boresights.maybe(), waveforms.maybe(), !&bistatic, !&jammers).join() {
             let new_pos = Position{
                 x: pos.x, 
                 y: pos.y, 
                 z: pos.z, 
-                direction: pos.direction
+                direction: antenna_direction(pos, orient, bore)
             };
             let new_wave = EMWave{
                 power: (ant.power*ant.gain), 
                 wavelength: ant.wavelength, 
                 frequency: ant.frequency, 
+                doppler: 0.0,
                 azimuth_width: ant.azimuth_beam_width, 
                 elevation_width: ant.elevation_beam_width
             };
             let new_entity = entities.create();
             updater.insert(new_entity, new_pos);
             updater.insert(new_entity, new_wave);
+            updater.insert(new_entity, WaveSource{
+                emitter: radar,
+                waveform_id: waveform.map_or(0, |wf| wf.id),
+                reflector: None,
+                bounces: 0
+            });
+            // Pulsed radars tag the wave with their waveform, so passive receivers can measure it
+            if let Some(waveform) = waveform {
+                updater.insert(new_entity, waveform.clone());
+            }
         }
     }
 }
@@ -53,6 +70,7 @@ mod tests {
                         power: 10000.0, 
                         wavelength: 1000.0, 
                         frequency: 100.0, 
+                        doppler: 0.0,
                         azimuth_width: 10.0, 
                         elevation_width: 20.0
                     });
@@ -67,6 +85,7 @@ mod tests {
         world.register::<EMWave>();
         world.register::<Position>();
         world.register::<Antenna>();
+        world.register::<WaveSource>();
 
         let mut sys = TransmitSignal;
         System::setup(&mut sys, &mut world);
@@ -97,4 +116,40 @@ mod tests {
         // Run test 
         tester.run_now(&world);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_jammers_do_not_transmit() {
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<Antenna>();
+        world.register::<Jammer>();
+
+        let mut sys = TransmitSignal;
+        System::setup(&mut sys, &mut world);
+
+        // A self-screening jammer carries an antenna for its noise, but is no radar
+        let _jammer = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 0.0, direction: 5.0})
+        .with(Antenna{
+            frequency: 100.0,
+            gain: 10.0,
+            power: 1000.0,
+            wavelength: 1000.0,
+            azimuth_beam_width: 10.0,
+            elevation_beam_width: 20.0
+        }).with(Jammer{
+            mode: JammingMode::Barrage,
+            center_frequency: 100.0,
+            bandwidth: 10.0
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        assert_eq!(world.read_storage::<EMWave>().count(), 0);
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+// Removes every wave once all receivers have had a chance to hear it. Runs at the end of each tick.
+pub struct WaveCleanupSystem;
+impl<'a> System<'a> for WaveCleanupSystem {
+    type SystemData = (
+        ReadStorage<'a, EMWave>,
+        Entities<'a>,
+    );
+
+    fn run(&mut self, (emissions, entities) : Self::SystemData) {
+        for (wave, _) in (&*entities, &emissions).join() {
+            if let Err(e) = entities.delete(wave) {
+                println!("{:?}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wave_cleanup() {
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+
+        let mut sys = WaveCleanupSystem;
+        System::setup(&mut sys, &mut world);
+
+        let _wave = world.create_entity()
+        .with(Position{x: 0.0, y: 0.0, z: 0.0, direction: 0.0})
+        .with(EMWave{
+            power: 1.0,
+            wavelength: 1.0,
+            frequency: 1.0,
+            doppler: 0.0,
+            azimuth_width: 20.0,
+            elevation_width: 20.0
+        }).build();
+        let _target = world.create_entity()
+        .with(Position{x: 100.0, y: 0.0, z: 0.0, direction: 0.0})
+        .build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+
+        // Only the wave is removed
+        assert_eq!(world.read_storage::<EMWave>().count(), 0);
+        assert_eq!(world.read_storage::<Position>().count(), 1);
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+use rand_distr::{Distribution, Normal};
+
+// Generates volume clutter echoes from rain cells inside each radar beam.
+// Range bins share the depth of the Clutter resource's range resolution.
+pub struct WeatherClutterSystem;
+impl<'a> System<'a> for WeatherClutterSystem {
+    type SystemData = (
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, EMWave>,
+        ReadStorage<'a, WaveSource>,
+        ReadStorage<'a, RainCell>,
+        Read<'a, Clutter>,
+        Read<'a, Weather>,
+        Write<'a, SimulationRng>,
+        Entities<'a>,
+        Read<'a, LazyUpdate>,
+    );
+
+    fn run(&mut self, (positions, emissions, sources, rain_cells, clutter, weather, mut rng, entities, updater): Self::SystemData) {
+        if clutter.range_resolution <= 0.0 {
+            return;
+        }
+        for (em, em_pos, source) in (&emissions, &positions, sources.maybe()).join() {
+            for (cell_entity, cell, cell_pos) in (&*entities, &rain_cells, &positions).join() {
+                let center_range = slant_range(em_pos, cell_pos);
+                let angle = incident_angle(em_pos, cell_pos);
+                // Widen the beam by the angle the cell subtends so a cell on the beam edge is still seen
+                let half_size = if center_range > cell.radius { (cell.radius / center_range).asin().to_degrees() } else { 180.0 };
+                if !check_illumination(em.azimuth_width + 2.0 * half_size, em_pos.direction, angle) {
+                    continue;
+                }
+                let spread = Normal::new(0.0, cell.velocity_spread.max(0.0)).unwrap();
+                let eta = rain_reflectivity(cell.rain_rate, em.wavelength);
+                let (sin_a, cos_a) = angle.to_radians().sin_cos();
+
+                // Bins are centered half a resolution cell in from the edges of the sphere
+                let mut range = (center_range - cell.radius).max(0.0) + clutter.range_resolution / 2.0;
+                while range < center_range + cell.radius {
+                    // Illuminated volume is the smaller of the beam and the slice of the sphere at this range
+                    let depth = range - center_range;
+                    let slice_area = std::f32::consts::PI * (cell.radius.powi(2) - depth.powi(2)).max(0.0);
+                    let beam_area = std::f32::consts::PI / 4.0
+                        * (range * em.azimuth_width.to_radians()) * (range * em.elevation_width.to_radians());
+                    let volume = slice_area.min(beam_area) * clutter.range_resolution;
+                    let sigma = eta * volume * clutter_fluctuation(ClutterStatistics::Rayleigh, &mut rng.rng);
+
+                    let bin = Position{
+                        x: em_pos.x + range * cos_a,
+                        y: em_pos.y + range * sin_a,
+                        z: cell_pos.z,
+                        direction: (angle + 180.0) % 360.0
+                    };
+                    let density = em.power * atmospheric_loss(em.frequency, range, &weather)
+                        * rain_cell_loss(em_pos, &bin, em.frequency, (&rain_cells, &positions).join())
+                        / (4.0 * std::f32::consts::PI * range.powi(2));
+                    // Drops move with the wind plus turbulence, positive away from the radar
+                    let radial_velocity = cell.wind_x * cos_a + cell.wind_y * sin_a + spread.sample(&mut rng.rng);
+
+                    let new_entity = entities.create();
+                    updater.insert(new_entity, bin);
+                    updater.insert(new_entity, EMWave{
+                        power: density * sigma,
+                        wavelength: em.wavelength,
+                        frequency: em.frequency,
+                        doppler: em.doppler + radial_doppler(em.frequency, radial_velocity),
+                        azimuth_width: 20.0,
+                        elevation_width: 20.0
+                    });
+                    if let Some(source) = source {
+                        updater.insert(new_entity, WaveSource{reflector: Some(cell_entity), bounces: source.bounces + 1, ..*source});
+                    }
+                    range += clutter.range_resolution;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weather_clutter() {
+        pub struct Tester;
+        impl<'a> System<'a> for Tester {
+            type SystemData = (
+                ReadStorage<'a, EMWave>,
+                ReadStorage<'a, Position>
+            );
+
+            fn run(&mut self, (em_waves, positions): Self::SystemData) {
+                // The radar's own wave plus four bins from 9 km to 11 km
+                assert_eq!(em_waves.count(), 5);
+                for (em_wave, pos) in (&em_waves, &positions).join() {
+                    if pos.direction == 180.0 {
+                        assert!(em_wave.power > 0.0);
+                        // 10 m/s tailwind moves the drops away, lowering the frequency
+                        assert!(em_wave.doppler < 0.0);
+                    }
+                }
+            }
+        }
+
+        // Create world
+        let mut world = World::new();
+
+        // Register the components to be used
+        world.register::<EMWave>();
+        world.register::<Position>();
+        world.register::<RainCell>();
+
+        world.insert(Clutter{
+            range_resolution: 500.0,
+            ..Clutter::default()
+        });
+
+        let mut sys = WeatherClutterSystem;
+        System::setup(&mut sys, &mut world);
+
+        let mut tester = Tester;
+        System::setup(&mut tester, &mut world);
+
+        // Create EMWave Entity
+        let _em_wave = world.create_entity()
+        .with(EMWave{
+            frequency: 3e9,
+            doppler: 0.0,
+            power: 1000.0,
+            wavelength: 0.1,
+            azimuth_width: 2.0,
+            elevation_width: 2.0
+        }).with(Position{
+            x: 0.0,
+            y: 0.0,
+            z: 1000.0,
+            direction: 0.0
+        }).build();
+
+        // Create rain cell entity
+        let _rain = world.create_entity()
+        .with(Position{
+            x: 10_000.0,
+            y: 0.0,
+            z: 1000.0,
+            direction: 0.0
+        }).with(RainCell{
+            radius: 1000.0,
+            rain_rate: 10.0,
+            wind_x: 10.0,
+            wind_y: 0.0,
+            velocity_spread: 1.0
+        }).build();
+
+        // Run the system
+        sys.run_now(&world);
+        world.maintain();
+        // Run test
+        tester.run_now(&world);
+    }
+}